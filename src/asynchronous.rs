@@ -0,0 +1,550 @@
+//! Async driver using [`embedded-hal-async`] traits. Enabled with the `async` feature.
+//!
+//! [`embedded-hal-async`]: https://github.com/rust-embedded/embedded-hal
+
+use core::future::{poll_fn, Future};
+use core::pin::pin;
+use core::task::Poll;
+
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital::Wait;
+use embedded_hal_async::spi::SpiDevice;
+
+use crate::configuration::{decode_cold_junction_temperature, decode_voltage,
+    decode_cold_junction_code, cold_junction_code_to_temperature, cold_junction_code_to_millicelsius,
+    decode_linearized_code, linearized_code_to_temperature, linearized_code_to_millicelsius,
+    encode_cold_junction_thresholds, decode_cold_junction_thresholds,
+    encode_thermocouple_thresholds, decode_thermocouple_thresholds,
+    encode_cold_junction_offset, decode_cold_junction_offset, decode_fault_status,
+    encode_cold_junction_temperature, RegisterValue};
+use crate::registers::Registers;
+use crate::{CMode, CompensatedReading, DeviceErrors, Error, FaultMask, FaultModes, Max31856Options, NoPin, OneShot, PinId, RawReading, Reading, Temperature, TemperatureDelta, ThermocoupleType};
+
+/// Async Max31856 Precision Thermocouple to Digital Converter with Linearization
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Max31856Async<SPI, FP, DP = NoPin> {
+    spi: SPI,
+    fault: FP,
+    drdy: DP,
+    config: Max31856Options,
+    auto_clear_faults: bool,
+}
+
+impl<SPI> Max31856Async<SPI, NoPin, NoPin>
+where
+    SPI: SpiDevice,
+{
+    /// Create a new instance of Max31856Async with FAULT and DRDY pins left unconnected
+    pub fn new_without_pins(spi: SPI) -> Self {
+        Max31856Async::new(spi, NoPin)
+    }
+}
+
+impl<SPI, FP> Max31856Async<SPI, FP, NoPin>
+where
+    SPI: SpiDevice,
+{
+    /// Create a new instance of Max31856Async
+    pub fn new(spi: SPI, fault_pin: FP) -> Self {
+        Max31856Async {
+            spi,
+            fault: fault_pin,
+            drdy: NoPin,
+            config: Max31856Options::default(),
+            auto_clear_faults: false,
+        }
+    }
+
+    /// Add the DRDY pin, which is asserted low when a conversion has completed
+    pub fn with_drdy_pin<DP: Wait>(self, drdy_pin: DP) -> Max31856Async<SPI, FP, DP> {
+        Max31856Async {
+            spi: self.spi,
+            fault: self.fault,
+            drdy: drdy_pin,
+            config: self.config,
+            auto_clear_faults: self.auto_clear_faults,
+        }
+    }
+}
+
+impl<SPI, FP, DP> Max31856Async<SPI, FP, DP>
+where
+    SPI: SpiDevice,
+    FP: Wait,
+{
+    /// Wait for the FAULT pin to assert and read and decode the fault status register.
+    /// Device faults are returned as data like poll_fault() of the blocking driver.
+    pub async fn wait_for_fault(&mut self) -> Result<DeviceErrors, Error> {
+        self.fault.wait_for_low().await.map_err(Error::pin(PinId::Fault))?;
+        self.faults().await
+    }
+}
+
+impl<SPI, FP, DP> Max31856Async<SPI, FP, DP>
+where
+    SPI: SpiDevice,
+    DP: Wait,
+{
+    /// Wait for the DRDY pin to assert and read the linearized and
+    /// cold-junction-compensated thermocouple temperature value.
+    /// In normally off mode a one-shot conversion is triggered first.
+    /// In automatic mode each conversion is returned only once, since
+    /// reading the result deasserts DRDY.
+    /// The wait is bounded by the one-shot conversion time. If DRDY does not
+    /// assert, e.g. because conversions are suspended by an overvoltage or
+    /// undervoltage fault, the device faults are returned as Error::Device,
+    /// or Error::Timeout if there are none.
+    pub async fn read_when_ready<D: DelayNs>(&mut self, delay: &mut D) -> Result<Temperature, Error> {
+        if self.config.type_selection.voltage_gain().is_some() {
+            return Err(Error::InvalidArgument);
+        }
+        if let CMode::NormallyOff = self.config.conversion_mode {
+            self.trigger_one_shot().await?;
+        }
+        let timeout = self.config.one_shot_conversion_time_us();
+        match select(self.drdy.wait_for_low(), delay.delay_us(timeout)).await {
+            Ok(result) => result.map_err(Error::pin(PinId::DataReady))?,
+            Err(()) => {
+                let faults = self.faults().await?;
+                return Err(if faults.any() { Error::Device(faults) } else { Error::Timeout });
+            }
+        }
+        self.read_linearized_temperature().await
+    }
+}
+
+// Runs both futures until one of them completes. The output of the first is
+// returned as Ok and the output of the second as Err.
+async fn select<A: Future, B: Future>(first: A, second: B) -> Result<A::Output, B::Output> {
+    let mut first = pin!(first);
+    let mut second = pin!(second);
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = first.as_mut().poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+        if let Poll::Ready(output) = second.as_mut().poll(cx) {
+            return Poll::Ready(Err(output));
+        }
+        Poll::Pending
+    }).await
+}
+
+impl<SPI, FP, DP> Max31856Async<SPI, FP, DP>
+where
+    SPI: SpiDevice,
+{
+    /// Parse options and write to C0 and C1 registers.
+    pub async fn send_config(&mut self) -> Result<(), Error> {
+        self.write_registers(&[Registers::CR0.write_address, self.config.extract_c0()]).await?;
+        self.write_registers(&[Registers::CR1.write_address, self.config.extract_c1()]).await
+    }
+
+    /// Read C0 and C1 registers and decode them into options.
+    /// The stored configuration is not modified.
+    pub async fn read_config(&mut self) -> Result<Max31856Options, Error> {
+        let mut buffer = [0u8; 3]; // One byte address, C0 and C1 register values
+        buffer[0] = Registers::CR0.read_address;
+        self.read_registers(&mut buffer).await?;
+        Max31856Options::from_registers(buffer[1], buffer[2])
+    }
+
+    /// Check that the configuration on the sensor matches the stored configuration.
+    /// Returns false if the sensor has lost its settings, e.g. after a power cycle.
+    /// The one shot bit is ignored since it self clears after each conversion.
+    pub async fn verify_config(&mut self) -> Result<bool, Error> {
+        let mut device = self.read_config().await?;
+        let mut expected = self.config;
+        device.one_shot_conversion = OneShot::NoConversion;
+        expected.one_shot_conversion = OneShot::NoConversion;
+        Ok(device == expected)
+    }
+
+    /// Get a reference of stored configuration. This can be then used to modify certain
+    /// values. send_config() can then be used to write it to the sensor.
+    pub fn config(&mut self) -> &mut Max31856Options {
+        &mut self.config
+    }
+
+    /// Write the fault mask register. Masked faults are still reported by
+    /// fault_status() but do not assert the FAULT output.
+    pub async fn set_fault_mask(&mut self, mask: FaultMask) -> Result<(), Error> {
+        self.write_registers(&[Registers::MASK.write_address, mask.to_register()]).await
+    }
+
+    /// Read the fault mask register
+    pub async fn fault_mask(&mut self) -> Result<FaultMask, Error> {
+        let mut buffer = [0u8; 2]; // One byte value from Fault mask register
+        buffer[0] = Registers::MASK.read_address;
+        self.read_registers(&mut buffer).await?;
+        Ok(FaultMask::from_register(buffer[1]))
+    }
+
+    /// Set the cold-junction low and high fault thresholds.
+    /// See [`Max31856::set_cold_junction_thresholds()`](crate::Max31856::set_cold_junction_thresholds)
+    pub async fn set_cold_junction_thresholds(&mut self, low: Temperature, high: Temperature) -> Result<(), Error> {
+        self.write_cold_junction_thresholds(low, high).await
+    }
+
+    /// Set the cold-junction low and high fault thresholds in millidegrees Celsius.
+    /// See [`Max31856::set_cold_junction_thresholds_millicelsius()`](crate::Max31856::set_cold_junction_thresholds_millicelsius)
+    pub async fn set_cold_junction_thresholds_millicelsius(&mut self, low: i32, high: i32) -> Result<(), Error> {
+        self.write_cold_junction_thresholds(low, high).await
+    }
+
+    async fn write_cold_junction_thresholds<T: RegisterValue>(&mut self, low: T, high: T) -> Result<(), Error> {
+        let [high, low] = encode_cold_junction_thresholds(low, high)?;
+        self.write_registers(&[Registers::CJHF.write_address, high, low]).await
+    }
+
+    /// Get the cold-junction low and high fault thresholds as (low, high)
+    pub async fn cold_junction_thresholds(&mut self) -> Result<(Temperature, Temperature), Error> {
+        self.read_cold_junction_thresholds().await
+    }
+
+    /// Get the cold-junction low and high fault thresholds in millidegrees Celsius
+    /// as (low, high)
+    pub async fn cold_junction_thresholds_millicelsius(&mut self) -> Result<(i32, i32), Error> {
+        self.read_cold_junction_thresholds().await
+    }
+
+    async fn read_cold_junction_thresholds<T: RegisterValue>(&mut self) -> Result<(T, T), Error> {
+        let mut buffer = [0u8; 3]; // One byte address, high and low thresholds
+        buffer[0] = Registers::CJHF.read_address;
+        self.read_registers(&mut buffer).await?;
+        Ok(decode_cold_junction_thresholds([buffer[1], buffer[2]]))
+    }
+
+    /// Set the linearized thermocouple temperature low and high fault thresholds.
+    /// See [`Max31856::set_thermocouple_thresholds()`](crate::Max31856::set_thermocouple_thresholds)
+    pub async fn set_thermocouple_thresholds(&mut self, low: Temperature, high: Temperature) -> Result<(), Error> {
+        self.write_thermocouple_thresholds(low, high).await
+    }
+
+    /// Set the linearized thermocouple temperature low and high fault thresholds in millidegrees Celsius.
+    /// See [`Max31856::set_thermocouple_thresholds_millicelsius()`](crate::Max31856::set_thermocouple_thresholds_millicelsius)
+    pub async fn set_thermocouple_thresholds_millicelsius(&mut self, low: i32, high: i32) -> Result<(), Error> {
+        self.write_thermocouple_thresholds(low, high).await
+    }
+
+    async fn write_thermocouple_thresholds<T: RegisterValue>(&mut self, low: T, high: T) -> Result<(), Error> {
+        let [high_msb, high_lsb, low_msb, low_lsb] = encode_thermocouple_thresholds(low, high)?;
+        self.write_registers(&[Registers::LTHFTH.write_address, high_msb, high_lsb, low_msb, low_lsb]).await
+    }
+
+    /// Get the linearized thermocouple temperature low and high fault thresholds
+    /// as (low, high)
+    pub async fn thermocouple_thresholds(&mut self) -> Result<(Temperature, Temperature), Error> {
+        self.read_thermocouple_thresholds().await
+    }
+
+    /// Get the linearized thermocouple temperature low and high fault thresholds
+    /// in millidegrees Celsius as (low, high)
+    pub async fn thermocouple_thresholds_millicelsius(&mut self) -> Result<(i32, i32), Error> {
+        self.read_thermocouple_thresholds().await
+    }
+
+    async fn read_thermocouple_thresholds<T: RegisterValue>(&mut self) -> Result<(T, T), Error> {
+        let mut buffer = [0u8; 5]; // One byte address, two bytes each for high and low thresholds
+        buffer[0] = Registers::LTHFTH.read_address;
+        self.read_registers(&mut buffer).await?;
+        Ok(decode_thermocouple_thresholds([buffer[1], buffer[2], buffer[3], buffer[4]]))
+    }
+
+    /// Set the cold-junction temperature offset.
+    /// See [`Max31856::set_cold_junction_offset()`](crate::Max31856::set_cold_junction_offset)
+    pub async fn set_cold_junction_offset(&mut self, offset: TemperatureDelta) -> Result<(), Error> {
+        self.write_cold_junction_offset(offset).await
+    }
+
+    /// Set the cold-junction temperature offset in millidegrees Celsius.
+    /// See [`Max31856::set_cold_junction_offset_millicelsius()`](crate::Max31856::set_cold_junction_offset_millicelsius)
+    pub async fn set_cold_junction_offset_millicelsius(&mut self, offset: i32) -> Result<(), Error> {
+        self.write_cold_junction_offset(offset).await
+    }
+
+    async fn write_cold_junction_offset<T: RegisterValue>(&mut self, offset: T) -> Result<(), Error> {
+        self.write_registers(&[Registers::CJTO.write_address, encode_cold_junction_offset(offset)?]).await
+    }
+
+    /// Get the cold-junction temperature offset
+    pub async fn cold_junction_offset(&mut self) -> Result<TemperatureDelta, Error> {
+        self.read_cold_junction_offset().await
+    }
+
+    /// Get the cold-junction temperature offset in millidegrees Celsius
+    pub async fn cold_junction_offset_millicelsius(&mut self) -> Result<i32, Error> {
+        self.read_cold_junction_offset().await
+    }
+
+    async fn read_cold_junction_offset<T: RegisterValue>(&mut self) -> Result<T, Error> {
+        let mut buffer = [0u8; 2]; // One byte value from Cold-junction offset register
+        buffer[0] = Registers::CJTO.read_address;
+        self.read_registers(&mut buffer).await?;
+        Ok(decode_cold_junction_offset(buffer[1]))
+    }
+
+    /// Get the measured value of cold-junction temperature without
+    /// the value in the Cold-Junction Offset register.
+    pub async fn raw_cold_junction_temperature(&mut self) -> Result<Temperature, Error> {
+        let mut buffer = [0u8; 4]; // One byte address, offset and two bytes of temperature data
+        buffer[0] = Registers::CJTO.read_address;
+        self.read_registers(&mut buffer).await?;
+        let offset: TemperatureDelta = decode_cold_junction_offset(buffer[1]);
+        Ok(decode_cold_junction_temperature([buffer[2], buffer[3]]) - offset)
+    }
+
+    /// Write an externally measured cold-junction temperature.
+    /// See [`Max31856::set_cold_junction_temperature()`](crate::Max31856::set_cold_junction_temperature)
+    pub async fn set_cold_junction_temperature(&mut self, temperature: Temperature) -> Result<(), Error> {
+        self.write_cold_junction_temperature(temperature).await
+    }
+
+    /// Write an externally measured cold-junction temperature in millidegrees Celsius.
+    /// See [`Max31856::set_cold_junction_temperature_millicelsius()`](crate::Max31856::set_cold_junction_temperature_millicelsius)
+    pub async fn set_cold_junction_temperature_millicelsius(&mut self, temperature: i32) -> Result<(), Error> {
+        self.write_cold_junction_temperature(temperature).await
+    }
+
+    async fn write_cold_junction_temperature<T: RegisterValue>(&mut self, temperature: T) -> Result<(), Error> {
+        if !self.config.cold_junction_sensor_disable {
+            return Err(Error::InvalidArgument);
+        }
+        let [high, low] = encode_cold_junction_temperature(temperature)?;
+        // The device only accepts CJTH and CJTL writes while its sensor is disabled
+        self.write_registers(&[Registers::CR0.write_address, self.config.extract_c0_without_one_shot()]).await?;
+        self.write_registers(&[Registers::CJTH.write_address, high, low]).await
+    }
+
+    /// Get the measured value of cold-junction temperature
+    /// plus the value in the Cold-Junction Offset register
+    pub async fn cold_junction_temperature(&mut self) -> Result<Temperature, Error> {
+        self.cold_junction_temperature_raw().await.map(cold_junction_code_to_temperature)
+    }
+
+    /// Get the cold-junction temperature including the offset as signed 14 bit code
+    /// with a resolution of 0.015625°C, without any floating point math
+    pub async fn cold_junction_temperature_raw(&mut self) -> Result<i16, Error> {
+        let mut buffer = [0u8; 3]; // Two bytes of temperature data
+        buffer[0] = Registers::CJTH.read_address;
+        self.read_registers(&mut buffer).await?;
+        Ok(decode_cold_junction_code([buffer[1], buffer[2]]))
+    }
+
+    /// Get the cold-junction temperature including the offset in millidegrees Celsius
+    pub async fn cold_junction_temperature_millicelsius(&mut self) -> Result<i32, Error> {
+        self.cold_junction_temperature_raw().await.map(cold_junction_code_to_millicelsius)
+    }
+
+    /// Get the cold-junction temperature including the offset in °C as fixed point number
+    #[cfg(feature = "fixed")]
+    pub async fn cold_junction_temperature_fixed(&mut self) -> Result<fixed::types::I16F16, Error> {
+        let code = self.cold_junction_temperature_raw().await?;
+        // 6 fractional bits
+        Ok(fixed::types::I16F16::from_bits(i32::from(code) << 10))
+    }
+
+    /// Read cold-junction temperature, thermocouple temperature and fault status
+    /// in a single transaction, so all values belong to the same conversion.
+    /// No conversion is triggered.
+    pub async fn read_all(&mut self) -> Result<Reading, Error> {
+        self.read_all_raw().await.map(Reading::from)
+    }
+
+    /// Read the register codes of cold-junction temperature, thermocouple temperature
+    /// and fault status in a single transaction, without any floating point math.
+    pub async fn read_all_raw(&mut self) -> Result<RawReading, Error> {
+        // One byte address, two bytes of cj, three bytes of tc data and fault status
+        let mut buffer = [0u8; 7];
+        buffer[0] = Registers::CJTH.read_address;
+        self.read_registers(&mut buffer).await?;
+        let mut registers = [0u8; 6];
+        registers.copy_from_slice(&buffer[1..]);
+        let reading = RawReading::from_registers(registers);
+        self.auto_clear(reading.faults).await?;
+        Ok(reading)
+    }
+
+    /// Get the linearized and cold-junction-compensated thermocouple
+    /// temperature value. Returns InvalidArgument when a voltage mode is selected,
+    /// see read_voltage().
+    /// In normally off mode a one-shot conversion is triggered but not waited for,
+    /// so the value of the previous conversion is returned. Use read_one_shot() to
+    /// wait for the triggered conversion.
+    pub async fn temperature(&mut self) -> Result<Temperature, Error> {
+        self.temperature_raw().await.map(linearized_code_to_temperature)
+    }
+
+    /// Get the linearized and cold-junction-compensated thermocouple temperature as
+    /// signed 19 bit code with a resolution of 0.0078125°C, without any floating point math.
+    /// See temperature().
+    pub async fn temperature_raw(&mut self) -> Result<i32, Error> {
+        if self.config.type_selection.voltage_gain().is_some() {
+            return Err(Error::InvalidArgument);
+        }
+        if let CMode::NormallyOff = self.config.conversion_mode {
+            self.trigger_one_shot().await?;
+        }
+        self.read_linearized_code().await
+    }
+
+    /// Get the linearized and cold-junction-compensated thermocouple temperature
+    /// in millidegrees Celsius. See temperature().
+    pub async fn temperature_millicelsius(&mut self) -> Result<i32, Error> {
+        self.temperature_raw().await.map(linearized_code_to_millicelsius)
+    }
+
+    /// Get the linearized and cold-junction-compensated thermocouple temperature
+    /// in °C as fixed point number. See temperature().
+    #[cfg(feature = "fixed")]
+    pub async fn temperature_fixed(&mut self) -> Result<fixed::types::I16F16, Error> {
+        let code = self.temperature_raw().await?;
+        // 7 fractional bits
+        Ok(fixed::types::I16F16::from_bits(code << 9))
+    }
+
+    /// Get the thermocouple input voltage in microvolts when a voltage mode
+    /// is selected as thermocouple type, otherwise InvalidArgument is returned.
+    /// In normally off mode a one-shot conversion is triggered and waited for,
+    /// see read_one_shot().
+    pub async fn read_voltage<D: DelayNs>(&mut self, delay: &mut D) -> Result<f32, Error> {
+        let gain = self.config.type_selection.voltage_gain().ok_or(Error::InvalidArgument)?;
+        if let CMode::NormallyOff = self.config.conversion_mode {
+            self.trigger_one_shot().await?;
+            delay.delay_us(self.config.one_shot_conversion_time_us()).await;
+        }
+        let mut buffer = [0u8; 4]; // One byte address, three bytes of voltage data
+        buffer[0] = Registers::LTCBH.read_address;
+        self.read_registers(&mut buffer).await?;
+        Ok(decode_voltage([buffer[1], buffer[2], buffer[3]], gain))
+    }
+
+    /// Read the input voltage and cold-junction temperature of the same conversion in
+    /// voltage mode and compensate and linearize them in software, e.g. with a
+    /// ThermocoupleType or a linearization for other thermocouples. See Max31856::read_compensated().
+    pub async fn read_compensated<L, D>(&mut self, linearization: &L, delay: &mut D) -> Result<CompensatedReading, Error>
+    where
+        L: crate::linearization::Linearization + ?Sized,
+        D: DelayNs,
+    {
+        let gain = self.config.type_selection.voltage_gain().ok_or(Error::InvalidArgument)?;
+        if let CMode::NormallyOff = self.config.conversion_mode {
+            self.trigger_one_shot().await?;
+            delay.delay_us(self.config.one_shot_conversion_time_us()).await;
+        }
+        // One byte address, two bytes of cj, three bytes of voltage data and fault status
+        let mut buffer = [0u8; 7];
+        buffer[0] = Registers::CJTH.read_address;
+        self.read_registers(&mut buffer).await?;
+        let mut registers = [0u8; 6];
+        registers.copy_from_slice(&buffer[1..]);
+        let reading = CompensatedReading::from_registers(registers, gain, linearization)?;
+        self.auto_clear(reading.faults).await?;
+        Ok(reading)
+    }
+
+    /// Read a software compensated reading and the linearized temperature of the chip
+    /// for the same thermocouple type from a second conversion.
+    /// See [`Max31856::read_compensated_with_chip()`](crate::Max31856::read_compensated_with_chip)
+    pub async fn read_compensated_with_chip<D>(&mut self, thermocouple_type: ThermocoupleType, delay: &mut D) -> Result<(CompensatedReading, Temperature), Error>
+    where
+        D: DelayNs,
+    {
+        if self.config.conversion_mode == CMode::AutomaticConversion
+            || thermocouple_type.voltage_gain().is_some() {
+            return Err(Error::InvalidArgument);
+        }
+        let reading = self.read_compensated(&thermocouple_type, delay).await?;
+        let mut options = self.config;
+        options.type_selection(thermocouple_type);
+        self.write_registers(&[Registers::CR1.write_address, options.extract_c1()]).await?;
+        self.trigger_one_shot().await?;
+        delay.delay_us(self.config.one_shot_conversion_time_us()).await;
+        let code = self.read_linearized_code().await;
+        // Restore the voltage mode before returning any read error
+        self.write_registers(&[Registers::CR1.write_address, self.config.extract_c1()]).await?;
+        Ok((reading, linearized_code_to_temperature(code?)))
+    }
+
+    /// Trigger a one-shot conversion, wait until it has completed and read the
+    /// linearized and cold-junction-compensated thermocouple temperature value.
+    /// The wait time is derived from the noise rejection filter and averaging mode.
+    /// Only available in normally off mode, otherwise InvalidArgument is returned.
+    pub async fn read_one_shot<D: DelayNs>(&mut self, delay: &mut D) -> Result<Temperature, Error> {
+        if self.config.conversion_mode == CMode::AutomaticConversion
+            || self.config.type_selection.voltage_gain().is_some() {
+            return Err(Error::InvalidArgument);
+        }
+        self.trigger_one_shot().await?;
+        delay.delay_us(self.config.one_shot_conversion_time_us()).await;
+        self.read_linearized_temperature().await
+    }
+
+    /// Read and decode all bits of the fault status register.
+    /// Device faults are returned as data, Err is only returned on communication errors.
+    pub async fn faults(&mut self) -> Result<DeviceErrors, Error> {
+        let mut buffer = [0u8; 2]; // One byte value from Fault status register
+        buffer[0] = Registers::SR.read_address;
+        self.read_registers(&mut buffer).await?;
+        let faults = DeviceErrors::from_register(buffer[1]);
+        self.auto_clear(faults).await?;
+        Ok(faults)
+    }
+
+    /// Clear the fault status register and deassert the FAULT output in interrupt
+    /// fault mode. The rest of C0 is written from the stored configuration
+    /// without triggering a one-shot conversion.
+    pub async fn clear_faults(&mut self) -> Result<(), Error> {
+        self.write_registers(&[Registers::CR0.write_address, self.config.extract_c0_fault_clear()]).await
+    }
+
+    /// Clear faults automatically after they have been read and reported
+    /// by faults(), fault_status() or read_all() in interrupt fault mode
+    pub fn set_auto_clear_faults(&mut self, enable: bool) {
+        self.auto_clear_faults = enable;
+    }
+
+    async fn auto_clear(&mut self, faults: DeviceErrors) -> Result<(), Error> {
+        if self.auto_clear_faults && faults.any() && self.config.fault_mode == FaultModes::Interrupt {
+            self.clear_faults().await?;
+        }
+        Ok(())
+    }
+
+    /// Check if any of the faults are triggered
+    pub async fn fault_status(&mut self) -> Result<(), Error> {
+        let mut buffer = [0u8; 2]; // One byte value from Fault status register
+        buffer[0] = Registers::SR.read_address;
+        self.read_registers(&mut buffer).await?;
+        self.auto_clear(DeviceErrors::from_register(buffer[1])).await?;
+        decode_fault_status(buffer[1])
+    }
+
+    async fn read_registers(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+        let address = buffer[0];
+        self.spi.transfer_in_place(buffer).await.map_err(Error::spi(address))?;
+        trace!("read register 0x{=u8:02X}: {=[u8]:02X}", address, &buffer[1..]);
+        Ok(())
+    }
+
+    async fn write_registers(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let address = bytes[0] & 0x7F;
+        trace!("write register 0x{=u8:02X}: {=[u8]:02X}", address, &bytes[1..]);
+        self.spi.write(bytes).await.map_err(Error::spi(address))
+    }
+
+    async fn trigger_one_shot(&mut self) -> Result<(), Error> {
+        self.write_registers(&[Registers::CR0.write_address, self.config.extract_c0_one_shot()]).await
+    }
+
+    async fn read_linearized_temperature(&mut self) -> Result<Temperature, Error> {
+        self.read_linearized_code().await.map(linearized_code_to_temperature)
+    }
+
+    async fn read_linearized_code(&mut self) -> Result<i32, Error> {
+        let mut buffer = [0u8; 4]; // One byte address, three bytes of temperature data
+        buffer[0] = Registers::LTCBH.read_address;
+        self.read_registers(&mut buffer).await?;
+        Ok(decode_linearized_code([buffer[1], buffer[2], buffer[3]]))
+    }
+}
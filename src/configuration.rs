@@ -1,3 +1,6 @@
+use core::convert::TryFrom;
+use crate::{Error, Temperature, TemperatureDelta};
+
 pub(crate) enum FaultBits{
 }
 impl FaultBits {
@@ -13,6 +16,8 @@ impl FaultBits {
 
 /// Errors reported by the device.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeviceErrors {
     /// The Cold-Junction temperature is outside of the normal operating range.
     pub cold_junction_out_of_range: bool,
@@ -42,20 +47,288 @@ pub struct DeviceErrors {
     pub open_circuit: bool,
 }
 
+/// Temperatures and fault status of a single conversion
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Reading {
+    /// Linearized and cold-junction-compensated thermocouple temperature
+    pub thermocouple: Temperature,
+    /// Cold-junction temperature, including the cold-junction offset
+    pub cold_junction: Temperature,
+    /// Faults reported by the device at the time of the reading
+    pub faults: DeviceErrors,
+}
+
+impl From<RawReading> for Reading {
+    fn from(raw: RawReading) -> Self {
+        Reading {
+            thermocouple: linearized_code_to_temperature(raw.thermocouple),
+            cold_junction: cold_junction_code_to_temperature(raw.cold_junction),
+            faults: raw.faults,
+        }
+    }
+}
+
+/// Register codes and fault status of a single conversion, without any floating point math
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RawReading {
+    /// Linearized and cold-junction-compensated thermocouple temperature as
+    /// signed 19 bit code with a resolution of 0.0078125°C
+    pub thermocouple: i32,
+    /// Cold-junction temperature, including the cold-junction offset, as
+    /// signed 14 bit code with a resolution of 0.015625°C
+    pub cold_junction: i16,
+    /// Faults reported by the device at the time of the reading
+    pub faults: DeviceErrors,
+}
+
+impl RawReading {
+    // Decodes the CJTH, CJTL, LTCBH, LTCBM, LTCBL and SR register values
+    pub(crate) fn from_registers(bytes: [u8; 6]) -> RawReading {
+        RawReading {
+            cold_junction: decode_cold_junction_code([bytes[0], bytes[1]]),
+            thermocouple: decode_linearized_code([bytes[2], bytes[3], bytes[4]]),
+            faults: DeviceErrors::from_register(bytes[5]),
+        }
+    }
+
+    /// Thermocouple temperature in millidegrees Celsius
+    pub fn thermocouple_millicelsius(&self) -> i32 {
+        linearized_code_to_millicelsius(self.thermocouple)
+    }
+
+    /// Cold-junction temperature in millidegrees Celsius
+    pub fn cold_junction_millicelsius(&self) -> i32 {
+        cold_junction_code_to_millicelsius(self.cold_junction)
+    }
+}
+
+/// Thermocouple temperature compensated and linearized in software from a voltage
+/// mode conversion, together with the values it was computed from
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CompensatedReading {
+    /// Hot junction temperature computed with the linearization
+    pub thermocouple: Temperature,
+    /// Thermocouple input voltage in microvolts
+    pub voltage: f32,
+    /// Cold-junction temperature, including the cold-junction offset
+    pub cold_junction: Temperature,
+    /// Faults reported by the device at the time of the reading
+    pub faults: DeviceErrors,
+}
+
+impl CompensatedReading {
+    // Decodes the CJTH, CJTL, LTCBH, LTCBM, LTCBL and SR register values of a
+    // voltage mode conversion and compensates them with the linearization
+    pub(crate) fn from_registers<L>(bytes: [u8; 6], gain: u32, linearization: &L) -> Result<Self, Error>
+    where
+        L: crate::linearization::Linearization + ?Sized,
+    {
+        let cold_junction = decode_cold_junction_temperature([bytes[0], bytes[1]]);
+        let ltcb = [bytes[2], bytes[3], bytes[4]];
+        let voltage = decode_voltage(ltcb, gain);
+        let temperature = linearization.compensate(f64::from(voltage) / 1000.0, f64::from(cold_junction.celsius()))?;
+        Ok(CompensatedReading {
+            thermocouple: Temperature::from_celsius(temperature as f32),
+            voltage,
+            cold_junction,
+            faults: DeviceErrors::from_register(bytes[5]),
+        })
+    }
+}
+
+/// A single fault reported by the device
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Fault {
+    /// The Cold-Junction temperature is outside of the normal operating range.
+    ColdJunctionOutOfRange,
+    /// The Thermocouple Hot Junction temperature is outside of the normal operating range.
+    ThermocoupleOutOfRange,
+    /// The Cold-Junction temperature is higher than the cold-junction temperature high threshold.
+    ColdJunctionHigh,
+    /// The Cold-Junction temperature is lower than the cold-junction temperature low threshold.
+    ColdJunctionLow,
+    /// The Thermocouple Temperature is higher than the thermocouple temperature high threshold.
+    ThermocoupleHigh,
+    /// The Thermocouple Temperature is lower than the thermocouple temperature low threshold.
+    ThermocoupleLow,
+    /// The input voltage is negative or greater than VDD.
+    OvervoltageUndervoltage,
+    /// An open circuit such as broken thermocouple wires has been detected.
+    OpenCircuit,
+}
+
+impl Fault {
+    const ALL: [Fault; 8] = [
+        Fault::ColdJunctionOutOfRange,
+        Fault::ThermocoupleOutOfRange,
+        Fault::ColdJunctionHigh,
+        Fault::ColdJunctionLow,
+        Fault::ThermocoupleHigh,
+        Fault::ThermocoupleLow,
+        Fault::OvervoltageUndervoltage,
+        Fault::OpenCircuit,
+    ];
+}
+
+impl DeviceErrors {
+    /// Check if any fault is reported
+    pub fn any(&self) -> bool {
+        self.iter().next().is_some()
+    }
+
+    /// Check if a fault is reported that makes the thermocouple temperature invalid:
+    /// overvoltage or undervoltage, open circuit, or a temperature out of the
+    /// normal operating range. The threshold faults are not critical.
+    pub fn is_critical(&self) -> bool {
+        self.overvoltage_undervoltage
+            || self.open_circuit
+            || self.thermocouple_out_of_range
+            || self.cold_junction_out_of_range
+    }
+
+    /// Check if other faults may be missing. An overvoltage or undervoltage fault
+    /// suspends conversions and the detection of other faults until it is gone.
+    pub fn masks_other_faults(&self) -> bool {
+        self.overvoltage_undervoltage
+    }
+
+    /// Check if the given fault is reported
+    pub fn contains(&self, fault: Fault) -> bool {
+        match fault {
+            Fault::ColdJunctionOutOfRange => self.cold_junction_out_of_range,
+            Fault::ThermocoupleOutOfRange => self.thermocouple_out_of_range,
+            Fault::ColdJunctionHigh => self.cold_junction_high,
+            Fault::ColdJunctionLow => self.cold_junction_low,
+            Fault::ThermocoupleHigh => self.thermocouple_high,
+            Fault::ThermocoupleLow => self.thermocouple_low,
+            Fault::OvervoltageUndervoltage => self.overvoltage_undervoltage,
+            Fault::OpenCircuit => self.open_circuit,
+        }
+    }
+
+    /// Iterate over the reported faults
+    pub fn iter(&self) -> impl Iterator<Item = Fault> {
+        let errors = *self;
+        Fault::ALL.iter().copied().filter(move |fault| errors.contains(*fault))
+    }
+
+    // Decodes every bit of the fault status register
+    pub(crate) fn from_register(value: u8) -> DeviceErrors {
+        DeviceErrors {
+            cold_junction_out_of_range: value & FaultBits::CJ_RANGE != 0,
+            thermocouple_out_of_range: value & FaultBits::TC_RANGE != 0,
+            cold_junction_high: value & FaultBits::CJ_HIGH != 0,
+            cold_junction_low: value & FaultBits::CJ_LOW != 0,
+            thermocouple_high: value & FaultBits::TC_HIGH != 0,
+            thermocouple_low: value & FaultBits::TC_LOW != 0,
+            overvoltage_undervoltage: value & FaultBits::OVUV != 0,
+            open_circuit: value & FaultBits::OPEN != 0,
+        }
+    }
+}
+
+/// Fault mask register. A masked fault is still reported in the fault status
+/// register but does not assert the FAULT output.
+/// The default matches the factory default of the device, where every fault is masked.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FaultMask {
+    /// Mask the cold-junction high fault.
+    pub cold_junction_high: bool,
+    /// Mask the cold-junction low fault.
+    pub cold_junction_low: bool,
+    /// Mask the thermocouple temperature high fault.
+    pub thermocouple_high: bool,
+    /// Mask the thermocouple temperature low fault.
+    pub thermocouple_low: bool,
+    /// Mask the overvoltage or undervoltage input fault.
+    pub overvoltage_undervoltage: bool,
+    /// Mask the thermocouple open-circuit fault.
+    pub open_circuit: bool,
+}
+
+impl FaultMask {
+    // Bits 7 and 6 are reserved and read back as 1
+    const RESERVED: u8 = 0b1100_0000;
+
+    pub(crate) fn to_register(self) -> u8 {
+        let mut value = FaultMask::RESERVED;
+        if self.cold_junction_high {
+            value |= FaultBits::CJ_HIGH;
+        }
+        if self.cold_junction_low {
+            value |= FaultBits::CJ_LOW;
+        }
+        if self.thermocouple_high {
+            value |= FaultBits::TC_HIGH;
+        }
+        if self.thermocouple_low {
+            value |= FaultBits::TC_LOW;
+        }
+        if self.overvoltage_undervoltage {
+            value |= FaultBits::OVUV;
+        }
+        if self.open_circuit {
+            value |= FaultBits::OPEN;
+        }
+        value
+    }
+
+    pub(crate) fn from_register(value: u8) -> FaultMask {
+        FaultMask {
+            cold_junction_high: value & FaultBits::CJ_HIGH != 0,
+            cold_junction_low: value & FaultBits::CJ_LOW != 0,
+            thermocouple_high: value & FaultBits::TC_HIGH != 0,
+            thermocouple_low: value & FaultBits::TC_LOW != 0,
+            overvoltage_undervoltage: value & FaultBits::OVUV != 0,
+            open_circuit: value & FaultBits::OPEN != 0,
+        }
+    }
+}
+
+impl Default for FaultMask {
+    fn default() -> Self {
+        FaultMask::from_register(0xFF)
+    }
+}
+
 
 /// Conversion mode
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CMode {
     /// 0 = Normally Off mode (default)
+    #[cfg_attr(feature = "serde", serde(rename = "normally_off"))]
     NormallyOff = 0,
     /// 1 = Automatic Conversion mode. Conversions occur continuously every 100ms (nominal).
+    #[cfg_attr(feature = "serde", serde(rename = "automatic"))]
     AutomaticConversion = 1,
 }
 
+impl TryFrom<u8> for CMode {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(CMode::NormallyOff),
+            1 => Ok(CMode::AutomaticConversion),
+            _ => Err(Error::InvalidArgument),
+        }
+    }
+}
+
 /// One-Shot Modes
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OneShot {
     /// 0 = No conversions requested (default)
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     NoConversion = 0,
     /// 1 = This causes a single cold-junction and thermocouple conversion
     /// to take place when Conversion Mode bit =0 (normally off mode).
@@ -64,22 +337,55 @@ pub enum OneShot {
     // is triggered when CS goes high at the end of the transaction.
     // A single conversion requires approximately 143ms in 60Hz filter mode
     // or 169ms in 50Hz filter mode to complete. This bit self clears to 0.
+    #[cfg_attr(feature = "serde", serde(rename = "one_shot"))]
     OneShotConversion = 1,
 }
 
+impl TryFrom<u8> for OneShot {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(OneShot::NoConversion),
+            1 => Ok(OneShot::OneShotConversion),
+            _ => Err(Error::InvalidArgument),
+        }
+    }
+}
+
 /// Open circuit detection modes
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OCFaultModes {
     /// 00 Disabled
+    #[cfg_attr(feature = "serde", serde(rename = "disabled"))]
     Disabled = 0,
     /// 01 Enabled  RS < 5kΩ
+    #[cfg_attr(feature = "serde", serde(rename = "5k"))]
     Enabled5k = 1,
     /// 10 Enabled 40kΩ > RS > 5kΩ; Time constant < 2ms
+    #[cfg_attr(feature = "serde", serde(rename = "40k_below_2ms"))]
     Enabled40k = 2,
     /// 11 Enabled 40kΩ > RS > 5kΩ; Time constant > 2ms
+    #[cfg_attr(feature = "serde", serde(rename = "40k_above_2ms"))]
     Enabled40k2ms = 3,
 }
 
+impl TryFrom<u8> for OCFaultModes {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(OCFaultModes::Disabled),
+            1 => Ok(OCFaultModes::Enabled5k),
+            2 => Ok(OCFaultModes::Enabled40k),
+            3 => Ok(OCFaultModes::Enabled40k2ms),
+            _ => Err(Error::InvalidArgument),
+        }
+    }
+}
+
 /// Fault modes
 // 0 = Comparator Mode. The FAULT output and respective fault bit reflects
 // the state of any nonmasked faults by asserting when the fault condition is true,
@@ -90,25 +396,57 @@ pub enum OCFaultModes {
 // until a 1 is written to the Fault Status Clear bit. This deasserts FAULT
 // and respective fault bit until a new fault is detected
 // (note that this may occur immediately if the fault condition is still in place).
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FaultModes {
     /// Comparator Mode
+    #[cfg_attr(feature = "serde", serde(rename = "comparator"))]
     Comparator = 0,
     /// Interrupt Mode
+    #[cfg_attr(feature = "serde", serde(rename = "interrupt"))]
     Interrupt = 1,
 }
 
+impl TryFrom<u8> for FaultModes {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(FaultModes::Comparator),
+            1 => Ok(FaultModes::Interrupt),
+            _ => Err(Error::InvalidArgument),
+        }
+    }
+}
+
 /// Noise rejection filter Modes
 // Note: Change the notch frequency only while in the “Normally Off” mode
 // – not in the Automatic conversion mode.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NoiseRejectionMode {
     /// Noise rejection for 60Hz
+    #[cfg_attr(feature = "serde", serde(rename = "60hz"))]
     Reject60Hz = 0,
     /// Noise rejection for 50Hz
+    #[cfg_attr(feature = "serde", serde(rename = "50hz"))]
     Reject50Hz = 1,
 }
 
+impl TryFrom<u8> for NoiseRejectionMode {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(NoiseRejectionMode::Reject60Hz),
+            1 => Ok(NoiseRejectionMode::Reject50Hz),
+            _ => Err(Error::InvalidArgument),
+        }
+    }
+}
+
 pub(crate) struct C0Mask {}
 
 impl C0Mask {
@@ -138,51 +476,126 @@ impl C1Mask {
 // = tCONV + (samples -1) x 20mS (50Hz rejection)
 // The Averaging Mode settings SHOULD NOT be changed while
 // conversions are taking place.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AveragingMode {
     /// One sample for averaging - Default
+    #[cfg_attr(feature = "serde", serde(rename = "1"))]
     OneSample = 0,
     /// Two samples for averaging
+    #[cfg_attr(feature = "serde", serde(rename = "2"))]
     TwoSamples = 1,
     /// Four samples for averaging
+    #[cfg_attr(feature = "serde", serde(rename = "4"))]
     FourSamples = 2,
     /// Eight samples for averaging
+    #[cfg_attr(feature = "serde", serde(rename = "8"))]
     EightSamples = 3,
     /// Sixteen samples for averaging
+    #[cfg_attr(feature = "serde", serde(rename = "16"))]
     SixteenSamples = 4,
 }
 
+impl AveragingMode {
+    pub(crate) fn samples(self) -> u32 {
+        1 << (self as u32)
+    }
+}
+
+impl TryFrom<u8> for AveragingMode {
+    type Error = Error;
+
+    // 1xx = 16 samples
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(AveragingMode::OneSample),
+            1 => Ok(AveragingMode::TwoSamples),
+            2 => Ok(AveragingMode::FourSamples),
+            3 => Ok(AveragingMode::EightSamples),
+            4..=7 => Ok(AveragingMode::SixteenSamples),
+            _ => Err(Error::InvalidArgument),
+        }
+    }
+}
+
 /// Thermoucouple types
-// 10xx = Voltage Mode, Gain = 8. Code = 8 x 1.6 x 217 x VIN
-// 11xx = Voltage Mode, Gain = 32. Code = 32 x 1.6 x 217 x VIN
+// 10xx = Voltage Mode, Gain = 8. Code = 8 x 1.6 x 2^17 x VIN
+// 11xx = Voltage Mode, Gain = 32. Code = 32 x 1.6 x 2^17 x VIN
 // Where Code is 19 bit signed number from TC registers
 // and VIN is thermocouple input voltage
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ThermocoupleType {
     /// B type thermocouple
+    #[cfg_attr(feature = "serde", serde(rename = "b"))]
     BType = 0,
     /// E type thermocouple
+    #[cfg_attr(feature = "serde", serde(rename = "e"))]
     EType = 1,
     /// J type thermocouple
+    #[cfg_attr(feature = "serde", serde(rename = "j"))]
     JType = 2,
     /// K type thermocouple
+    #[cfg_attr(feature = "serde", serde(rename = "k"))]
     KType = 3,
     /// N type thermocouple
+    #[cfg_attr(feature = "serde", serde(rename = "n"))]
     NType = 4,
     /// R type thermocouple
+    #[cfg_attr(feature = "serde", serde(rename = "r"))]
     RType = 5,
     /// S type thermocouple
+    #[cfg_attr(feature = "serde", serde(rename = "s"))]
     SType = 6,
     /// T type thermocouple
+    #[cfg_attr(feature = "serde", serde(rename = "t"))]
     TType = 7,
-    /// Voltage Mode, Gain = 8. Code = 8 x 1.6 x 217 x VIN
+    /// Voltage Mode, Gain = 8. Code = 8 x 1.6 x 2^17 x VIN
+    #[cfg_attr(feature = "serde", serde(rename = "voltage_gain_8"))]
     VModeGain8Type = 8,
-    /// Voltage Mode, Gain = 32. Code = 32 x 1.6 x 217 x VIN
-    VModeGain32Type = 9,
+    /// Voltage Mode, Gain = 32. Code = 32 x 1.6 x 2^17 x VIN
+    #[cfg_attr(feature = "serde", serde(rename = "voltage_gain_32"))]
+    VModeGain32Type = 12,
+}
+
+impl ThermocoupleType {
+    /// Gain of the input amplifier in voltage mode, None for thermocouple types
+    pub fn voltage_gain(self) -> Option<u32> {
+        match self {
+            ThermocoupleType::VModeGain8Type => Some(8),
+            ThermocoupleType::VModeGain32Type => Some(32),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<u8> for ThermocoupleType {
+    type Error = Error;
+
+    // 10xx = Voltage Mode, Gain = 8. 11xx = Voltage Mode, Gain = 32
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ThermocoupleType::BType),
+            1 => Ok(ThermocoupleType::EType),
+            2 => Ok(ThermocoupleType::JType),
+            3 => Ok(ThermocoupleType::KType),
+            4 => Ok(ThermocoupleType::NType),
+            5 => Ok(ThermocoupleType::RType),
+            6 => Ok(ThermocoupleType::SType),
+            7 => Ok(ThermocoupleType::TType),
+            8..=11 => Ok(ThermocoupleType::VModeGain8Type),
+            12..=15 => Ok(ThermocoupleType::VModeGain32Type),
+            _ => Err(Error::InvalidArgument),
+        }
+    }
 }
 
 /// Options that control configuration of Max31856.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Max31856Options {
     /// Option for conversion mode
     pub conversion_mode: CMode,
@@ -270,6 +683,50 @@ impl Max31856Options {
         self
     }
 
+    /// Maximum time in microseconds for a one-shot conversion (or the first
+    /// conversion in automatic mode) with the selected noise rejection filter
+    /// and averaging mode.
+    // tCONV is 155ms max with 60Hz rejection and 185ms max with 50Hz rejection.
+    // Each additional sample adds 33.33ms (60Hz) or 40ms (50Hz).
+    pub fn one_shot_conversion_time_us(&self) -> u32 {
+        let (t_conv, per_sample) = match self.noise_rejection_frequency {
+            NoiseRejectionMode::Reject60Hz => (155_000, 33_334),
+            NoiseRejectionMode::Reject50Hz => (185_000, 40_000),
+        };
+        t_conv + (self.average_samples.samples() - 1) * per_sample
+    }
+
+    /// Maximum time in microseconds between two conversion results in
+    /// automatic mode with the selected noise rejection filter and averaging
+    /// mode.
+    // tCONV is 90ms max with 60Hz rejection and 110ms max with 50Hz rejection,
+    // rounded up for the internal oscillator tolerance.
+    // Each additional sample adds 16.67ms (60Hz) or 20ms (50Hz).
+    pub fn automatic_conversion_time_us(&self) -> u32 {
+        let (t_conv, per_sample) = match self.noise_rejection_frequency {
+            NoiseRejectionMode::Reject60Hz => (100_000, 16_667),
+            NoiseRejectionMode::Reject50Hz => (120_000, 20_000),
+        };
+        t_conv + (self.average_samples.samples() - 1) * per_sample
+    }
+
+    // Extracts register value of C0 with the one shot bit set, leaving options unchanged.
+    pub(crate) fn extract_c0_one_shot(&self) -> u8 {
+        self.extract_c0() | (OneShot::OneShotConversion as u8) << C0Mask::ONE_SHOT_SHIFT
+    }
+
+    // Extracts register value of C0 with the one shot bit cleared, so no
+    // conversion is triggered.
+    pub(crate) fn extract_c0_without_one_shot(&self) -> u8 {
+        self.extract_c0() & !(1 << C0Mask::ONE_SHOT_SHIFT)
+    }
+
+    // Extracts register value of C0 with the fault status clear bit set and
+    // the one shot bit cleared, so no conversion is triggered.
+    pub(crate) fn extract_c0_fault_clear(&self) -> u8 {
+        self.extract_c0_without_one_shot() | 1 << C0Mask::FAULTCLR_SHIFT
+    }
+
     pub(crate) fn extract_c0(&self) -> u8 {
         let cmode = (self.conversion_mode as u8) << C0Mask::CMODE_SHIFT;
         let one_shot = (self.one_shot_conversion as u8) << C0Mask::ONE_SHOT_SHIFT;
@@ -287,6 +744,20 @@ impl Max31856Options {
 
         averaging_mode | tc_type
     }
+
+    // Decodes register values of C0 and C1 into options.
+    pub(crate) fn from_registers(c0: u8, c1: u8) -> Result<Max31856Options, Error> {
+        Ok(Max31856Options {
+            conversion_mode: CMode::try_from((c0 >> C0Mask::CMODE_SHIFT) & 0b1)?,
+            one_shot_conversion: OneShot::try_from((c0 >> C0Mask::ONE_SHOT_SHIFT) & 0b1)?,
+            open_circuit_fault_det: OCFaultModes::try_from((c0 >> C0Mask::OCFAULTPTR_SHIFT) & 0b11)?,
+            cold_junction_sensor_disable: (c0 >> C0Mask::CJ_SHIFT) & 0b1 != 0,
+            fault_mode: FaultModes::try_from((c0 >> C0Mask::FAULT_SHIFT) & 0b1)?,
+            noise_rejection_frequency: NoiseRejectionMode::try_from((c0 >> C0Mask::NOISEFLTR_SHIFT) & 0b1)?,
+            average_samples: AveragingMode::try_from((c1 >> C1Mask::AVGSELECT_SHIFT) & 0b111)?,
+            type_selection: ThermocoupleType::try_from((c1 >> C1Mask::TYPE_SHIFT) & 0b1111)?,
+        })
+    }
 }
 
 impl Default for Max31856Options {
@@ -294,3 +765,217 @@ impl Default for Max31856Options {
         Max31856Options::new()
     }
 }
+
+// Resolution of a temperature register, in °C per LSB for floating point math and
+// as fraction (numerator, denominator) of m°C per LSB for integer math
+#[derive(Clone, Copy)]
+pub(crate) struct Resolution {
+    celsius: f32,
+    millicelsius: (i32, i32),
+}
+
+// CJHF and CJLF
+const CJ_THRESHOLD_RESOLUTION: Resolution = Resolution { celsius: 1.0, millicelsius: (1000, 1) };
+// LTHFT and LTLFT
+const TC_THRESHOLD_RESOLUTION: Resolution = Resolution { celsius: 0.0625, millicelsius: (125, 2) };
+// CJTO
+const CJ_OFFSET_RESOLUTION: Resolution = Resolution { celsius: 0.0625, millicelsius: (125, 2) };
+// The 14 bit CJTH and CJTL value
+const CJ_TEMPERATURE_RESOLUTION: Resolution = Resolution { celsius: 0.015625, millicelsius: (125, 8) };
+
+// Value written to or read from a temperature register. Temperature and
+// TemperatureDelta use floating point math, m°C as i32 only integer math.
+pub(crate) trait RegisterValue: Sized {
+    // Converts to the two's complement code of a register with the given resolution
+    // and width. The value is rounded to the nearest step.
+    fn to_code(self, resolution: Resolution, bits: u32) -> Result<i32, Error>;
+
+    // Converts from a two's complement register code
+    fn from_code(code: i32, resolution: Resolution) -> Self;
+}
+
+impl RegisterValue for Temperature {
+    fn to_code(self, resolution: Resolution, bits: u32) -> Result<i32, Error> {
+        celsius_to_code(self.celsius(), resolution.celsius, bits)
+    }
+
+    fn from_code(code: i32, resolution: Resolution) -> Self {
+        Temperature::from_celsius(code_to_celsius(code, resolution.celsius))
+    }
+}
+
+impl RegisterValue for TemperatureDelta {
+    fn to_code(self, resolution: Resolution, bits: u32) -> Result<i32, Error> {
+        celsius_to_code(self.celsius(), resolution.celsius, bits)
+    }
+
+    fn from_code(code: i32, resolution: Resolution) -> Self {
+        TemperatureDelta::from_celsius(code_to_celsius(code, resolution.celsius))
+    }
+}
+
+impl RegisterValue for i32 {
+    fn to_code(self, resolution: Resolution, bits: u32) -> Result<i32, Error> {
+        let (numerator, denominator) = resolution.millicelsius;
+        // Twice the steps, so that halves are rounded away from zero by the truncating division
+        let steps = 2 * i64::from(self) * i64::from(denominator);
+        let rounding = if steps < 0 { -i64::from(numerator) } else { i64::from(numerator) };
+        let code = (steps + rounding) / (2 * i64::from(numerator));
+        check_code(code, bits)
+    }
+
+    // m°C are rounded to the nearest m°C
+    fn from_code(code: i32, resolution: Resolution) -> Self {
+        let (numerator, denominator) = resolution.millicelsius;
+        (2 * code * numerator + denominator).div_euclid(2 * denominator)
+    }
+}
+
+// Converts a temperature in °C to the two's complement code of a register with the
+// given resolution (°C per LSB) and width. The value is rounded to the nearest step.
+fn celsius_to_code(celsius: f32, resolution: f32, bits: u32) -> Result<i32, Error> {
+    if !celsius.is_finite() {
+        return Err(Error::InvalidArgument);
+    }
+    let steps = celsius / resolution;
+    let code = if steps < 0.0 { (steps - 0.5) as i64 } else { (steps + 0.5) as i64 };
+    check_code(code, bits)
+}
+
+// Checks that the code fits into a two's complement register of the given width
+fn check_code(code: i64, bits: u32) -> Result<i32, Error> {
+    let max = (1i64 << (bits - 1)) - 1;
+    let min = -(1i64 << (bits - 1));
+    if code < min || code > max {
+        return Err(Error::InvalidArgument);
+    }
+    Ok(code as i32)
+}
+
+// Converts a two's complement register code to a temperature in °C
+fn code_to_celsius(code: i32, resolution: f32) -> f32 {
+    code as f32 * resolution
+}
+
+pub(crate) fn linearized_code_to_temperature(code: i32) -> Temperature {
+    // multiply by measurement accuracy
+    Temperature::from_celsius(code as f32 * 0.0078125)
+}
+
+// 1/128 °C is 125/16 m°C, rounded to the nearest m°C
+pub(crate) fn linearized_code_to_millicelsius(code: i32) -> i32 {
+    (code * 125 + 8) >> 4
+}
+
+// Converts the LTCBH, LTCBM and LTCBL register values in voltage mode to microvolts.
+// Code = gain x 1.6 x 2^17 x VIN, so VIN in µV = Code x 625000 / (gain x 2^17)
+pub(crate) fn decode_voltage(bytes: [u8; 3], gain: u32) -> f32 {
+    decode_linearized_code(bytes) as f32 * 625_000.0 / (gain as f32 * 131_072.0)
+}
+
+// Converts the LTCBH, LTCBM and LTCBL register values to the signed 19 bit code
+pub(crate) fn decode_linearized_code(bytes: [u8; 3]) -> i32 {
+    // move bytes into int
+    let mut value: i32 = (bytes[0] as i32) << 16;
+    value += (bytes[1] as i32) << 8;
+    value += bytes[2] as i32;
+    // fill extra bits with msb for twos-complement representation with larger int
+    if bytes[0] & 0x80 == 0x80 {
+        value += 0xFF_i32 << 24;
+    }
+    // shift out least significant 5 bits because they are not used
+    value >> 5
+}
+
+// Converts a temperature to CJTH and CJTL register values.
+// The value is 14 bits left aligned, the two least significant bits are unused.
+pub(crate) fn encode_cold_junction_temperature<T: RegisterValue>(temperature: T) -> Result<[u8; 2], Error> {
+    let code = temperature.to_code(CJ_TEMPERATURE_RESOLUTION, 14)? as i16;
+    Ok((code << 2).to_be_bytes())
+}
+
+// Converts the CJTH and CJTL register values to a temperature in °C
+pub(crate) fn decode_cold_junction_temperature(bytes: [u8; 2]) -> Temperature {
+    cold_junction_code_to_temperature(decode_cold_junction_code(bytes))
+}
+
+// Converts the CJTH and CJTL register values to the signed 14 bit code,
+// the two least significant bits are not used
+pub(crate) fn decode_cold_junction_code(bytes: [u8; 2]) -> i16 {
+    i16::from_be_bytes(bytes) >> 2
+}
+
+pub(crate) fn cold_junction_code_to_temperature(code: i16) -> Temperature {
+    Temperature::from_celsius(code as f32 / 64.0)
+}
+
+// 1/64 °C is 125/8 m°C, rounded to the nearest m°C
+pub(crate) fn cold_junction_code_to_millicelsius(code: i16) -> i32 {
+    (i32::from(code) * 125 + 4) >> 3
+}
+
+// Encodes low and high thresholds as CJHF and CJLF register values
+pub(crate) fn encode_cold_junction_thresholds<T: RegisterValue>(low: T, high: T) -> Result<[u8; 2], Error> {
+    let low = low.to_code(CJ_THRESHOLD_RESOLUTION, 8)?;
+    let high = high.to_code(CJ_THRESHOLD_RESOLUTION, 8)?;
+    if low > high {
+        return Err(Error::InvalidArgument);
+    }
+    Ok([high as u8, low as u8])
+}
+
+// Decodes CJHF and CJLF register values to (low, high) thresholds
+pub(crate) fn decode_cold_junction_thresholds<T: RegisterValue>(bytes: [u8; 2]) -> (T, T) {
+    let high = T::from_code(bytes[0] as i8 as i32, CJ_THRESHOLD_RESOLUTION);
+    let low = T::from_code(bytes[1] as i8 as i32, CJ_THRESHOLD_RESOLUTION);
+    (low, high)
+}
+
+// Encodes low and high thresholds as LTHFTH, LTHFTL, LTLFTH and LTLFTL register values
+pub(crate) fn encode_thermocouple_thresholds<T: RegisterValue>(low: T, high: T) -> Result<[u8; 4], Error> {
+    let low = low.to_code(TC_THRESHOLD_RESOLUTION, 16)? as i16;
+    let high = high.to_code(TC_THRESHOLD_RESOLUTION, 16)? as i16;
+    if low > high {
+        return Err(Error::InvalidArgument);
+    }
+    let [high_msb, high_lsb] = high.to_be_bytes();
+    let [low_msb, low_lsb] = low.to_be_bytes();
+    Ok([high_msb, high_lsb, low_msb, low_lsb])
+}
+
+// Decodes LTHFTH, LTHFTL, LTLFTH and LTLFTL register values to (low, high) thresholds
+pub(crate) fn decode_thermocouple_thresholds<T: RegisterValue>(bytes: [u8; 4]) -> (T, T) {
+    let high = i16::from_be_bytes([bytes[0], bytes[1]]);
+    let low = i16::from_be_bytes([bytes[2], bytes[3]]);
+    (
+        T::from_code(i32::from(low), TC_THRESHOLD_RESOLUTION),
+        T::from_code(i32::from(high), TC_THRESHOLD_RESOLUTION),
+    )
+}
+
+// Encodes an offset as CJTO register value
+pub(crate) fn encode_cold_junction_offset<T: RegisterValue>(offset: T) -> Result<u8, Error> {
+    Ok(offset.to_code(CJ_OFFSET_RESOLUTION, 8)? as u8)
+}
+
+// Decodes CJTO register value to an offset
+pub(crate) fn decode_cold_junction_offset<T: RegisterValue>(value: u8) -> T {
+    T::from_code(value as i8 as i32, CJ_OFFSET_RESOLUTION)
+}
+
+// Checks the fault status register value for device errors
+pub(crate) fn decode_fault_status(error_id: u8) -> Result<(), Error> {
+    let errors = DeviceErrors::from_register(error_id);
+    //If overvoltage or undervoltage, all other errors might not be set
+    if errors.overvoltage_undervoltage {
+        return Err(Error::Device(DeviceErrors {
+            overvoltage_undervoltage: true,
+            ..DeviceErrors::default()
+        }));
+    }
+    if errors.any() {
+        Err(Error::Device(errors))
+    } else {
+        Ok(())
+    }
+}
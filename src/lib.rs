@@ -8,26 +8,62 @@
 //! Communication with MAX31856 only works with Spi Mode 1 or 3.
 //!
 //! Features:
+//! - FAULT and DRDY pins are optional. See: [`new_without_pins()`]
 //! - Modify default configuration. See: [`config()`]
-//! - Read/write configuration. See: [`send_config()`]
+//! - Read/write configuration. See: [`send_config()`] and [`read_config()`]
 //! - Read Linearized thermocouple temperature in Celcius. See: [`temperature()`]
+//! - Temperatures in Celsius, Fahrenheit or Kelvin, with `uom` conversions with the `uom` feature. See: [`Temperature`]
+//! - Wait for a one-shot conversion to complete. See: [`read_one_shot()`]
+//! - Wait for new conversion results with the DRDY pin. See: [`read_when_ready()`]
+//! - Non-blocking conversions. See: [`start_conversion()`] and [`try_read()`]
+//! - Async driver with the `async` feature. See: `Max31856Async`
+//! - Conversion mode tracked in the type. See: [`typestate`]
 //! - Read cold junction temperature. See: [`cold_junction_temperature()`]
-//! - Read Fault status. See: [`fault_status()`]
+//! - Integer and fixed point (with the `fixed` feature) temperatures, readings, thresholds and offsets without floating point math. See: [`temperature_millicelsius()`] and [`read_all_raw()`]
+//! - Read input voltage in microvolts in voltage mode. See: [`read_voltage()`]
+//! - Read Fault status. See: [`fault_status()`] and [`faults()`]
+//! - Clear faults in interrupt fault mode. See: [`clear_faults()`]
+//! - Interrupts with FAULT pin. See: [`poll_fault()`] and [`split_fault_pin()`]
+//! - Read temperatures and fault status of the same conversion. See: [`read_all()`]
+//! - Read/write fault mask register. See: [`set_fault_mask()`]
+//! - Read/write cold junction fault thresholds. See: [`set_cold_junction_thresholds()`]
+//! - Read/write linearized temperature fault thresholds. See: [`set_thermocouple_thresholds()`]
+//! - Read/write cold junction temperature offset. See: [`set_cold_junction_offset()`]
+//! - External temperature sensor for cold junction conversion. See: [`read_one_shot_with_cold_junction()`]
+//! - Errors keep the SPI or pin error kind and the register or pin being accessed. See: [`Error`]
+//! - `defmt::Format` for all public types and trace logs of register accesses with the `defmt` feature
+//! - `serde` serialization of the options, their enums and device errors with stable names with the `serde` feature
+//! - NIST ITS-90 reference functions in software. See: [`its90`]
+//! - Cold junction compensation in software from voltage mode, compared with the chip. See: [`read_compensated()`] and [`read_compensated_with_chip()`]
+//! - User-defined linearization tables and polynomials, e.g. for type C, L and U. See: [`linearization`]
 //!
+//! [`new_without_pins()`]: struct.Max31856.html#method.new_without_pins
 //! [`config()`]: struct.Max31856.html#method.config
 //! [`send_config()`]: struct.Max31856.html#method.send_config
+//! [`read_config()`]: struct.Max31856.html#method.read_config
 //! [`temperature()`]: struct.Max31856.html#method.temperature
+//! [`read_one_shot()`]: struct.Max31856.html#method.read_one_shot
+//! [`read_when_ready()`]: struct.Max31856.html#method.read_when_ready
+//! [`start_conversion()`]: struct.Max31856.html#method.start_conversion
+//! [`try_read()`]: struct.Max31856.html#method.try_read
 //! [`cold_junction_temperature()`]: struct.Max31856.html#method.cold_junction_temperature
+//! [`read_voltage()`]: struct.Max31856.html#method.read_voltage
+//! [`temperature_millicelsius()`]: struct.Max31856.html#method.temperature_millicelsius
+//! [`read_all_raw()`]: struct.Max31856.html#method.read_all_raw
+//! [`read_compensated()`]: struct.Max31856.html#method.read_compensated
+//! [`read_compensated_with_chip()`]: struct.Max31856.html#method.read_compensated_with_chip
 //! [`fault_status()`]: struct.Max31856.html#method.fault_status
+//! [`faults()`]: struct.Max31856.html#method.faults
+//! [`clear_faults()`]: struct.Max31856.html#method.clear_faults
+//! [`poll_fault()`]: struct.Max31856.html#method.poll_fault
+//! [`split_fault_pin()`]: struct.Max31856.html#method.split_fault_pin
+//! [`read_all()`]: struct.Max31856.html#method.read_all
+//! [`set_fault_mask()`]: struct.Max31856.html#method.set_fault_mask
+//! [`set_cold_junction_thresholds()`]: struct.Max31856.html#method.set_cold_junction_thresholds
+//! [`set_thermocouple_thresholds()`]: struct.Max31856.html#method.set_thermocouple_thresholds
+//! [`set_cold_junction_offset()`]: struct.Max31856.html#method.set_cold_junction_offset
+//! [`read_one_shot_with_cold_junction()`]: struct.Max31856.html#method.read_one_shot_with_cold_junction
 //!
-//! Features in the next few versions:
-//! - Interrupts with FAULT pin
-//! - External temperature sensor for cold junction conversion
-//! - Read/write fault mask registers.
-//! - Read/write cold junction fault mask registers.
-//! - Read/write Linearized temperature fault registers.
-//! - Read/write cold junction temperature offset registers. 
-//! 
 //! ## Usage example
 //! ```
 //! use max31856;
@@ -41,10 +77,10 @@
 //!     // A default configuration is set on creation. It can be edited as follows
 //!     sensor.config().average_samples(max31856::AveragingMode::FourSamples);
 //!     let _ = sensor.send_config();
-//!     println!("Temperature: {}", sensor.temperature().unwrap());
+//!     println!("Temperature: {}", sensor.temperature().unwrap().celsius());
 //!     sensor.config().conversion_mode(max31856::CMode::AutomaticConversion);
 //!     let _ = sensor.send_config();
-//!     println!("Temperature: {}", sensor.temperature().unwrap());
+//!     println!("Temperature: {}", sensor.temperature().unwrap().celsius());
 //!     // Faults can be assessed via 
 //!     println!("Status: {:?}", sensor.fault_status()); 
 //!     Ok(())
@@ -57,43 +93,197 @@
 #![allow(dead_code)]
 
 extern crate embedded_hal as hal;
+use hal::delay::DelayNs;
 use hal::spi::{Mode, MODE_3};
-use configuration::FaultBits;
+use configuration::{C0Mask, decode_cold_junction_temperature, decode_voltage,
+    decode_cold_junction_code, cold_junction_code_to_temperature, cold_junction_code_to_millicelsius,
+    decode_linearized_code, linearized_code_to_temperature, linearized_code_to_millicelsius,
+    encode_cold_junction_thresholds, decode_cold_junction_thresholds,
+    encode_thermocouple_thresholds, decode_thermocouple_thresholds,
+    encode_cold_junction_offset, decode_cold_junction_offset, decode_fault_status,
+    encode_cold_junction_temperature, RegisterValue};
+
+// Logs with defmt::trace! if the defmt feature is enabled, otherwise does nothing
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "defmt")]
+        defmt::trace!($($arg)*);
+    };
+}
 
 mod configuration;
-pub use configuration::{CMode, OneShot, OCFaultModes, FaultModes, DeviceErrors, 
-    NoiseRejectionMode, AveragingMode, ThermocoupleType, Max31856Options};
+pub use configuration::{CMode, OneShot, OCFaultModes, FaultModes, DeviceErrors, Fault, FaultMask,
+    Reading, RawReading, CompensatedReading, NoiseRejectionMode, AveragingMode, ThermocoupleType, Max31856Options};
 mod registers;
 use registers::Registers;
+mod pins;
+mod temperature;
+pub use temperature::{Temperature, TemperatureDelta};
+pub use pins::{FaultPin, NoPin, OptionalPin, PinId};
+pub mod typestate;
+pub mod its90;
+pub mod linearization;
+#[cfg(feature = "async")]
+pub mod asynchronous;
+#[cfg(feature = "async")]
+pub use asynchronous::Max31856Async;
 
 /// Errors in this crate
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error {
     /// SPI communication error
-    Spi,
-    /// Pin setting error
-    Pin,
+    Spi {
+        /// Kind of the underlying SPI error
+        kind: hal::spi::ErrorKind,
+        /// Address of the (first) register being accessed, without the write bit
+        register: u8,
+    },
+    /// Pin reading error
+    Pin {
+        /// Kind of the underlying pin error
+        kind: hal::digital::ErrorKind,
+        /// Pin being read
+        pin: PinId,
+    },
     /// Invalid argument provided
     InvalidArgument,
+    /// The external cold-junction temperature could not be measured
+    ColdJunctionSource {
+        /// Kind of the underlying error of the source
+        kind: ColdJunctionErrorKind,
+    },
+    /// A conversion did not complete in time
+    Timeout,
     /// Errors from the device. 
     /// Can be more than one. If there is undervoltage or overvoltage, 
     /// other errors are not detected. Fix that first. Use DeviceError
+    /// Only returned by fault_status() and when a conversion did not complete,
+    /// faults() returns them as data.
     Device(DeviceErrors),
 }
 
+impl Error {
+    pub(crate) fn spi<E: hal::spi::Error>(register: u8) -> impl FnOnce(E) -> Error {
+        move |error| Error::Spi { kind: error.kind(), register }
+    }
+
+    pub(crate) fn pin<E: hal::digital::Error>(pin: PinId) -> impl FnOnce(E) -> Error {
+        move |error| Error::Pin { kind: error.kind(), pin }
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Spi { kind, register } => {
+                write!(f, "SPI error accessing register 0x{:02X}: {}", register, kind)
+            }
+            Error::Pin { kind, pin } => write!(f, "error reading {:?} pin: {}", pin, kind),
+            Error::InvalidArgument => f.write_str("invalid argument"),
+            Error::ColdJunctionSource { kind } => write!(f, "external cold-junction source failed: {}", kind),
+            Error::Timeout => f.write_str("timed out waiting for conversion"),
+            Error::Device(errors) => write!(f, "device fault: {:?}", errors),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Source of an externally measured cold-junction temperature, such as an RTD
+/// or a digital temperature sensor mounted on the isothermal block.
+/// Used when the internal cold-junction sensor is disabled.
+pub trait ColdJunctionSource {
+    /// Error of the measurement, its kind is kept in Error::ColdJunctionSource
+    type Error: ColdJunctionError;
+
+    /// Measure the cold-junction temperature
+    fn cold_junction_temperature(&mut self) -> Result<Temperature, Self::Error>;
+}
+
+/// Error of a ColdJunctionSource
+pub trait ColdJunctionError: core::fmt::Debug {
+    /// Convert the error to a generic kind
+    fn kind(&self) -> ColdJunctionErrorKind;
+}
+
+/// Generic kind of a ColdJunctionSource error
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ColdJunctionErrorKind {
+    /// Communication with the sensor failed, e.g. an I2C or SPI error
+    Communication,
+    /// The sensor is not ready, e.g. a conversion is still in progress
+    NotReady,
+    /// The measured temperature is outside of the range of the sensor
+    OutOfRange,
+    /// Any other error
+    Other,
+}
+
+impl ColdJunctionError for ColdJunctionErrorKind {
+    fn kind(&self) -> ColdJunctionErrorKind {
+        *self
+    }
+}
+
+impl ColdJunctionError for core::convert::Infallible {
+    fn kind(&self) -> ColdJunctionErrorKind {
+        match *self {}
+    }
+}
+
+impl core::fmt::Display for ColdJunctionErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ColdJunctionErrorKind::Communication => "communication with the sensor failed",
+            ColdJunctionErrorKind::NotReady => "the sensor is not ready",
+            ColdJunctionErrorKind::OutOfRange => "the temperature is out of range",
+            ColdJunctionErrorKind::Other => "other error",
+        })
+    }
+}
+
 /// SPI mode (CPOL = 1, CPHA = 1)
 pub const MODE: Mode = MODE_3; // See Table 5. Serial Interface Function
 
+// Interval of polling the DRDY pin in read_when_ready()
+const DRDY_POLL_INTERVAL_US: u32 = 1_000;
+
 
 /// Max31856 Precision Thermocouple to Digital Converter with Linearization
 #[derive(Debug, Default)]
-pub struct Max31856<SPI, FP> {
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Max31856<SPI, FP, DP = NoPin> {
     spi: SPI,
     fault: FP,
-    config: Max31856Options
+    drdy: DP,
+    config: Max31856Options,
+    conversion_started: bool,
+    conversion_started_at: u32,
+    auto_clear_faults: bool,
+}
+
+impl<SPI> Max31856<SPI, NoPin, NoPin>
+where
+    SPI: embedded_hal::spi::SpiDevice,
+{
+    /// Create a new instance of Max31856 with FAULT and DRDY pins left unconnected.
+    /// Pins can be added later with with_fault_pin() and with_drdy_pin().
+    pub fn new_without_pins(spi: SPI) -> Self {
+        Max31856 {
+            spi,
+            fault: NoPin,
+            drdy: NoPin,
+            config: Max31856Options::default(),
+            conversion_started: false,
+            conversion_started_at: 0,
+            auto_clear_faults: false,
+        }
+    }
 }
 
-impl<SPI, FP> Max31856<SPI, FP>
+impl<SPI, FP> Max31856<SPI, FP, NoPin>
 where
     SPI: embedded_hal::spi::SpiDevice,
     FP: hal::digital::InputPin,
@@ -103,10 +293,218 @@ where
         Max31856 {
             spi,
             fault: fault_pin,
+            drdy: NoPin,
             config: Max31856Options::default(),
+            conversion_started: false,
+            conversion_started_at: 0,
+            auto_clear_faults: false,
+        }
+    }
+}
+
+impl<SPI, FP> Max31856<SPI, FP, NoPin>
+where
+    SPI: embedded_hal::spi::SpiDevice,
+{
+    /// Add the DRDY pin, which is asserted low when a conversion has completed
+    pub fn with_drdy_pin<DP: hal::digital::InputPin>(self, drdy_pin: DP) -> Max31856<SPI, FP, DP> {
+        Max31856 {
+            spi: self.spi,
+            fault: self.fault,
+            drdy: drdy_pin,
+            config: self.config,
+            conversion_started: self.conversion_started,
+            conversion_started_at: self.conversion_started_at,
+            auto_clear_faults: self.auto_clear_faults,
+        }
+    }
+}
+
+impl<SPI, FP, DP> Max31856<SPI, FP, DP>
+where
+    SPI: embedded_hal::spi::SpiDevice,
+    FP: hal::digital::InputPin,
+{
+    /// Check if the FAULT pin is asserted, meaning a non-masked fault is present
+    pub fn is_fault_asserted(&mut self) -> Result<bool, Error> {
+        self.fault.is_low().map_err(Error::pin(PinId::Fault))
+    }
+
+    /// Read and decode the fault status register if the FAULT pin is asserted.
+    /// Returns None without any SPI traffic otherwise.
+    pub fn poll_fault(&mut self) -> Result<Option<DeviceErrors>, Error> {
+        if self.is_fault_asserted()? {
+            self.faults().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Split off the FAULT pin, e.g. to hand it to an interrupt handler.
+    /// The returned driver keeps all SPI functionality.
+    pub fn split_fault_pin(self) -> (Max31856<SPI, NoPin, DP>, FaultPin<FP>) {
+        let driver = Max31856 {
+            spi: self.spi,
+            fault: NoPin,
+            drdy: self.drdy,
+            config: self.config,
+            conversion_started: self.conversion_started,
+            conversion_started_at: self.conversion_started_at,
+            auto_clear_faults: self.auto_clear_faults,
+        };
+        (driver, FaultPin::new(self.fault))
+    }
+}
+
+impl<SPI, DP> Max31856<SPI, NoPin, DP>
+where
+    SPI: embedded_hal::spi::SpiDevice,
+{
+    /// Add the FAULT pin, which is asserted low when a non-masked fault is present
+    pub fn with_fault_pin<FP: hal::digital::InputPin>(self, fault_pin: FP) -> Max31856<SPI, FP, DP> {
+        Max31856 {
+            spi: self.spi,
+            fault: fault_pin,
+            drdy: self.drdy,
+            config: self.config,
+            conversion_started: self.conversion_started,
+            conversion_started_at: self.conversion_started_at,
+            auto_clear_faults: self.auto_clear_faults,
         }
     }
 
+    /// Join a FAULT pin that was split off with split_fault_pin()
+    pub fn join_fault_pin<FP: hal::digital::InputPin>(self, fault: FaultPin<FP>) -> Max31856<SPI, FP, DP> {
+        self.with_fault_pin(fault.release())
+    }
+}
+
+impl<SPI, FP, DP> Max31856<SPI, FP, DP>
+where
+    SPI: embedded_hal::spi::SpiDevice,
+    DP: hal::digital::InputPin,
+{
+    /// Check if the DRDY pin is asserted, meaning a new conversion result is available
+    pub fn is_data_ready(&mut self) -> Result<bool, Error> {
+        self.drdy.is_low().map_err(Error::pin(PinId::DataReady))
+    }
+
+    /// Wait for the DRDY pin to assert and read the linearized and
+    /// cold-junction-compensated thermocouple temperature value.
+    /// In normally off mode a one-shot conversion is triggered first. If DRDY
+    /// is still asserted from an earlier conversion that was not read, it is
+    /// deasserted before the trigger, so that result is not returned.
+    /// In automatic mode each conversion is returned only once, since
+    /// reading the result deasserts DRDY.
+    /// DRDY is polled every millisecond for up to the one-shot conversion time.
+    /// If it does not assert, e.g. because conversions are suspended by an
+    /// overvoltage or undervoltage fault, the device faults are returned as
+    /// Error::Device, or Error::Timeout if there are none.
+    pub fn read_when_ready<D: DelayNs>(&mut self, delay: &mut D) -> Result<Temperature, Error> {
+        if self.config.type_selection.voltage_gain().is_some() {
+            return Err(Error::InvalidArgument);
+        }
+        if let CMode::NormallyOff = self.config.conversion_mode {
+            if self.is_data_ready()? {
+                self.clear_data_ready()?;
+            }
+            self.trigger_one_shot()?;
+        }
+        let timeout = self.config.one_shot_conversion_time_us();
+        let mut waited = 0;
+        while !self.is_data_ready()? {
+            if waited >= timeout {
+                let faults = self.faults()?;
+                return Err(if faults.any() { Error::Device(faults) } else { Error::Timeout });
+            }
+            delay.delay_us(DRDY_POLL_INTERVAL_US);
+            waited += DRDY_POLL_INTERVAL_US;
+        }
+        self.read_linearized_temperature()
+    }
+}
+
+impl<SPI, FP, DP> Max31856<SPI, FP, DP>
+where
+    SPI: embedded_hal::spi::SpiDevice,
+    DP: OptionalPin,
+{
+    /// Start a conversion to be read with try_read(). The current time of a
+    /// free-running microsecond tick counter is used to detect completion
+    /// without DRDY pin, it may wrap around.
+    /// In normally off mode this triggers a one-shot conversion. A DRDY pin
+    /// still asserted from an earlier unread conversion is deasserted first.
+    /// In automatic mode conversions are already running and nothing is written.
+    pub fn start_conversion(&mut self, now_us: u32) -> Result<(), Error> {
+        if let CMode::NormallyOff = self.config.conversion_mode {
+            let drdy = self.drdy.is_asserted()
+                .map_err(|kind| Error::Pin { kind, pin: PinId::DataReady })?;
+            if drdy == Some(true) {
+                self.clear_data_ready()?;
+            }
+            self.trigger_one_shot()?;
+        }
+        self.conversion_started = true;
+        self.conversion_started_at = now_us;
+        Ok(())
+    }
+
+    /// Read the thermocouple and cold-junction temperatures once the conversion
+    /// started with start_conversion() has completed. Returns WouldBlock while
+    /// the conversion is in progress.
+    /// Completion is detected with the DRDY pin if it is connected. Otherwise
+    /// now_us from the same tick counter as in start_conversion() must be at least
+    /// the conversion time after the start or the previous read in automatic mode,
+    /// see one_shot_conversion_time_us() and automatic_conversion_time_us().
+    /// In normally off mode the self clearing one shot bit is checked as well.
+    /// Returns InvalidArgument if no conversion has been started.
+    pub fn try_read(&mut self, now_us: u32) -> nb::Result<Reading, Error> {
+        self.try_read_raw(now_us).map(Reading::from)
+    }
+
+    /// Read the register codes of a conversion started with start_conversion() once
+    /// it has completed, without any floating point math. See try_read().
+    pub fn try_read_raw(&mut self, now_us: u32) -> nb::Result<RawReading, Error> {
+        if !self.conversion_started {
+            return Err(nb::Error::Other(Error::InvalidArgument));
+        }
+        let drdy = self.drdy.is_asserted()
+            .map_err(|kind| Error::Pin { kind, pin: PinId::DataReady })?;
+        let elapsed = now_us.wrapping_sub(self.conversion_started_at);
+        let ready = match drdy {
+            Some(asserted) => asserted,
+            None => match self.config.conversion_mode {
+                CMode::NormallyOff => {
+                    elapsed >= self.config.one_shot_conversion_time_us() && !self.is_one_shot_pending()?
+                }
+                CMode::AutomaticConversion => elapsed >= self.config.automatic_conversion_time_us(),
+            },
+        };
+        if !ready {
+            return Err(nb::Error::WouldBlock);
+        }
+        let reading = self.read_all_raw()?;
+        match self.config.conversion_mode {
+            CMode::NormallyOff => self.conversion_started = false,
+            // The next conversion completes one conversion time after this one
+            CMode::AutomaticConversion => self.conversion_started_at = now_us,
+        }
+        Ok(reading)
+    }
+
+    fn is_one_shot_pending(&mut self) -> Result<bool, Error> {
+        let mut buffer = [0u8; 2]; // One byte value from C0 register
+        buffer[0] = Registers::CR0.read_address;
+        self.read_registers(&mut buffer)?;
+        Ok(buffer[1] & (1 << C0Mask::ONE_SHOT_SHIFT) != 0)
+    }
+}
+
+impl<SPI, FP, DP> Max31856<SPI, FP, DP>
+where
+    SPI: embedded_hal::spi::SpiDevice,
+{
+
     /// Parse options and write to C0 and C1 registers. 
     pub fn send_config(&mut self) -> Result<(), Error> {
         self.send_c0()?;
@@ -114,13 +512,31 @@ where
     }
 
     fn send_c0(&mut self) -> Result<(), Error> {
-        self.spi.write(&[Registers::CR0.write_address, self.config.extract_c0()])
-        .map_err(|_| Error::Spi)
+        self.write_registers(&[Registers::CR0.write_address, self.config.extract_c0()])
     }
 
     fn send_c1(&mut self) -> Result<(), Error> {
-        self.spi.write(&[Registers::CR1.write_address, self.config.extract_c1()])
-        .map_err(|_| Error::Spi)
+        self.write_registers(&[Registers::CR1.write_address, self.config.extract_c1()])
+    }
+
+    /// Read C0 and C1 registers and decode them into options.
+    /// The stored configuration is not modified.
+    pub fn read_config(&mut self) -> Result<Max31856Options, Error> {
+        let mut buffer = [0u8; 3]; // One byte address, C0 and C1 register values
+        buffer[0] = Registers::CR0.read_address;
+        self.read_registers(&mut buffer)?;
+        Max31856Options::from_registers(buffer[1], buffer[2])
+    }
+
+    /// Check that the configuration on the sensor matches the stored configuration.
+    /// Returns false if the sensor has lost its settings, e.g. after a power cycle.
+    /// The one shot bit is ignored since it self clears after each conversion.
+    pub fn verify_config(&mut self) -> Result<bool, Error> {
+        let mut device = self.read_config()?;
+        let mut expected = self.config;
+        device.one_shot_conversion = OneShot::NoConversion;
+        expected.one_shot_conversion = OneShot::NoConversion;
+        Ok(device == expected)
     }
 
     /// Get a reference of stored configuration. This can be then used to modify certain
@@ -128,114 +544,455 @@ where
     pub fn config(&mut self) -> &mut Max31856Options{
         &mut self.config
     }
-    //TODO: method for writing and reading fault mask register
+    /// Write the fault mask register. Masked faults are still reported by
+    /// fault_status() but do not assert the FAULT output.
+    pub fn set_fault_mask(&mut self, mask: FaultMask) -> Result<(), Error> {
+        self.write_registers(&[Registers::MASK.write_address, mask.to_register()])
+    }
+
+    /// Read the fault mask register
+    pub fn fault_mask(&mut self) -> Result<FaultMask, Error> {
+        let mut buffer = [0u8; 2]; // One byte value from Fault mask register
+        buffer[0] = Registers::MASK.read_address;
+        self.read_registers(&mut buffer)?;
+        Ok(FaultMask::from_register(buffer[1]))
+    }
+
+    /// Set the cold-junction low and high fault thresholds.
+    /// The thresholds have a resolution of 1°C and are rounded to the nearest degree.
+    /// Values outside of -128°C to 127°C, or a low threshold above the high
+    /// threshold, are rejected with InvalidArgument.
+    pub fn set_cold_junction_thresholds(&mut self, low: Temperature, high: Temperature) -> Result<(), Error> {
+        self.write_cold_junction_thresholds(low, high)
+    }
+
+    /// Set the cold-junction low and high fault thresholds in millidegrees Celsius,
+    /// without any floating point math. See set_cold_junction_thresholds().
+    pub fn set_cold_junction_thresholds_millicelsius(&mut self, low: i32, high: i32) -> Result<(), Error> {
+        self.write_cold_junction_thresholds(low, high)
+    }
+
+    fn write_cold_junction_thresholds<T: RegisterValue>(&mut self, low: T, high: T) -> Result<(), Error> {
+        let [high, low] = encode_cold_junction_thresholds(low, high)?;
+        // CJHF and CJLF are adjacent, the address auto-increments
+        self.write_registers(&[Registers::CJHF.write_address, high, low])
+    }
+
+    /// Get the cold-junction low and high fault thresholds as (low, high)
+    pub fn cold_junction_thresholds(&mut self) -> Result<(Temperature, Temperature), Error> {
+        self.read_cold_junction_thresholds()
+    }
+
+    /// Get the cold-junction low and high fault thresholds in millidegrees Celsius
+    /// as (low, high)
+    pub fn cold_junction_thresholds_millicelsius(&mut self) -> Result<(i32, i32), Error> {
+        self.read_cold_junction_thresholds()
+    }
+
+    fn read_cold_junction_thresholds<T: RegisterValue>(&mut self) -> Result<(T, T), Error> {
+        let mut buffer = [0u8; 3]; // One byte address, high and low thresholds
+        buffer[0] = Registers::CJHF.read_address;
+        self.read_registers(&mut buffer)?;
+        Ok(decode_cold_junction_thresholds([buffer[1], buffer[2]]))
+    }
+
+    /// Set the linearized thermocouple temperature low and high fault thresholds.
+    /// The thresholds have a resolution of 0.0625°C and are rounded to the nearest step.
+    /// Values outside of -2048°C to 2047.9375°C, or a low threshold above the high
+    /// threshold, are rejected with InvalidArgument.
+    pub fn set_thermocouple_thresholds(&mut self, low: Temperature, high: Temperature) -> Result<(), Error> {
+        self.write_thermocouple_thresholds(low, high)
+    }
+
+    /// Set the linearized thermocouple temperature low and high fault thresholds in
+    /// millidegrees Celsius, without any floating point math. See set_thermocouple_thresholds().
+    pub fn set_thermocouple_thresholds_millicelsius(&mut self, low: i32, high: i32) -> Result<(), Error> {
+        self.write_thermocouple_thresholds(low, high)
+    }
 
-    //TODO: method for setting cold junction high and low fault threshold
+    fn write_thermocouple_thresholds<T: RegisterValue>(&mut self, low: T, high: T) -> Result<(), Error> {
+        let [high_msb, high_lsb, low_msb, low_lsb] = encode_thermocouple_thresholds(low, high)?;
+        // LTHFTH, LTHFTL, LTLFTH and LTLFTL are adjacent, the address auto-increments
+        self.write_registers(&[Registers::LTHFTH.write_address, high_msb, high_lsb, low_msb, low_lsb])
+    }
+
+    /// Get the linearized thermocouple temperature low and high fault thresholds
+    /// as (low, high)
+    pub fn thermocouple_thresholds(&mut self) -> Result<(Temperature, Temperature), Error> {
+        self.read_thermocouple_thresholds()
+    }
 
-    //TODO: method for setting linearized temperature high and low threshold
+    /// Get the linearized thermocouple temperature low and high fault thresholds
+    /// in millidegrees Celsius as (low, high). Odd steps are rounded up to the next m°C.
+    pub fn thermocouple_thresholds_millicelsius(&mut self) -> Result<(i32, i32), Error> {
+        self.read_thermocouple_thresholds()
+    }
 
-    //TODO: method for cold junction temperature offset
+    fn read_thermocouple_thresholds<T: RegisterValue>(&mut self) -> Result<(T, T), Error> {
+        let mut buffer = [0u8; 5]; // One byte address, two bytes each for high and low thresholds
+        buffer[0] = Registers::LTHFTH.read_address;
+        self.read_registers(&mut buffer)?;
+        Ok(decode_thermocouple_thresholds([buffer[1], buffer[2], buffer[3], buffer[4]]))
+    }
+
+    /// Set the cold-junction temperature offset. The offset is added to the
+    /// measured cold-junction temperature before it is used for compensation and
+    /// reported by cold_junction_temperature().
+    /// The offset has a resolution of 0.0625°C and is rounded to the nearest step.
+    /// Values outside of -8°C to 7.9375°C are rejected with InvalidArgument.
+    pub fn set_cold_junction_offset(&mut self, offset: TemperatureDelta) -> Result<(), Error> {
+        self.write_cold_junction_offset(offset)
+    }
+
+    /// Set the cold-junction temperature offset in millidegrees Celsius,
+    /// without any floating point math. See set_cold_junction_offset().
+    pub fn set_cold_junction_offset_millicelsius(&mut self, offset: i32) -> Result<(), Error> {
+        self.write_cold_junction_offset(offset)
+    }
+
+    fn write_cold_junction_offset<T: RegisterValue>(&mut self, offset: T) -> Result<(), Error> {
+        self.write_registers(&[Registers::CJTO.write_address, encode_cold_junction_offset(offset)?])
+    }
+
+    /// Get the cold-junction temperature offset
+    pub fn cold_junction_offset(&mut self) -> Result<TemperatureDelta, Error> {
+        self.read_cold_junction_offset()
+    }
+
+    /// Get the cold-junction temperature offset in millidegrees Celsius.
+    /// Odd steps are rounded up to the next m°C.
+    pub fn cold_junction_offset_millicelsius(&mut self) -> Result<i32, Error> {
+        self.read_cold_junction_offset()
+    }
+
+    fn read_cold_junction_offset<T: RegisterValue>(&mut self) -> Result<T, Error> {
+        let mut buffer = [0u8; 2]; // One byte value from Cold-junction offset register
+        buffer[0] = Registers::CJTO.read_address;
+        self.read_registers(&mut buffer)?;
+        Ok(decode_cold_junction_offset(buffer[1]))
+    }
+
+    /// Get the measured value of cold-junction temperature without
+    /// the value in the Cold-Junction Offset register.
+    /// Offset and temperature are read in a single transaction.
+    pub fn raw_cold_junction_temperature(&mut self) -> Result<Temperature, Error> {
+        let mut buffer = [0u8; 4]; // One byte address, offset and two bytes of temperature data
+        buffer[0] = Registers::CJTO.read_address;
+        self.read_registers(&mut buffer)?;
+        let offset: TemperatureDelta = decode_cold_junction_offset(buffer[1]);
+        Ok(decode_cold_junction_temperature([buffer[2], buffer[3]]) - offset)
+    }
+
+    /// Write an externally measured cold-junction temperature, which is used
+    /// for compensation of the following conversions. Only available when the internal
+    /// cold-junction sensor is disabled in the configuration, otherwise InvalidArgument
+    /// is returned. C0 is written from the stored configuration first, so the sensor
+    /// is also disabled on the device. The temperature has a resolution of 0.015625°C
+    /// and a range of -128°C to 127.984375°C.
+    pub fn set_cold_junction_temperature(&mut self, temperature: Temperature) -> Result<(), Error> {
+        self.write_cold_junction_temperature(temperature)
+    }
+
+    /// Write an externally measured cold-junction temperature in millidegrees Celsius,
+    /// without any floating point math. See set_cold_junction_temperature().
+    pub fn set_cold_junction_temperature_millicelsius(&mut self, temperature: i32) -> Result<(), Error> {
+        self.write_cold_junction_temperature(temperature)
+    }
+
+    fn write_cold_junction_temperature<T: RegisterValue>(&mut self, temperature: T) -> Result<(), Error> {
+        if !self.config.cold_junction_sensor_disable {
+            return Err(Error::InvalidArgument);
+        }
+        let [high, low] = encode_cold_junction_temperature(temperature)?;
+        // The device only accepts CJTH and CJTL writes while its sensor is disabled
+        self.write_registers(&[Registers::CR0.write_address, self.config.extract_c0_without_one_shot()])?;
+        // CJTH and CJTL are adjacent, the address auto-increments
+        self.write_registers(&[Registers::CJTH.write_address, high, low])
+    }
+
+    /// Measure the cold-junction temperature with an external source, write it to the
+    /// sensor and read a one-shot conversion compensated with it.
+    /// See set_cold_junction_temperature() and read_one_shot().
+    pub fn read_one_shot_with_cold_junction<S, D>(&mut self, source: &mut S, delay: &mut D) -> Result<Temperature, Error>
+    where
+        S: ColdJunctionSource,
+        D: DelayNs,
+    {
+        let temperature = source.cold_junction_temperature()
+            .map_err(|error| Error::ColdJunctionSource { kind: error.kind() })?;
+        self.set_cold_junction_temperature(temperature)?;
+        self.read_one_shot(delay)
+    }
 
     /// Get the measured value of cold-junction temperature 
     /// plus the value in the Cold-Junction Offset register
-    pub fn cold_junction_temperature(&mut self) -> Result<f32, Error> {
+    pub fn cold_junction_temperature(&mut self) -> Result<Temperature, Error> {
+        self.cold_junction_temperature_raw().map(cold_junction_code_to_temperature)
+    }
+
+    /// Get the cold-junction temperature including the offset as signed 14 bit code
+    /// with a resolution of 0.015625°C, without any floating point math
+    pub fn cold_junction_temperature_raw(&mut self) -> Result<i16, Error> {
         let mut buffer = [0u8; 3]; // Two bytes of temperature data
         buffer[0] = Registers::CJTH.read_address;
-        self.spi.transfer_in_place(&mut buffer).map_err(|_| Error::Spi)?;
-        let mut value: i16 = (buffer[1] as i16) << 8;
-        value += buffer[2] as i16;
-        Ok(value as f32 / 256.0)
+        self.read_registers(&mut buffer)?;
+        Ok(decode_cold_junction_code([buffer[1], buffer[2]]))
+    }
+
+    /// Get the cold-junction temperature including the offset in millidegrees Celsius
+    pub fn cold_junction_temperature_millicelsius(&mut self) -> Result<i32, Error> {
+        self.cold_junction_temperature_raw().map(cold_junction_code_to_millicelsius)
+    }
+
+    /// Get the cold-junction temperature including the offset in °C as fixed point number
+    #[cfg(feature = "fixed")]
+    pub fn cold_junction_temperature_fixed(&mut self) -> Result<fixed::types::I16F16, Error> {
+        let code = self.cold_junction_temperature_raw()?;
+        // 6 fractional bits
+        Ok(fixed::types::I16F16::from_bits(i32::from(code) << 10))
+    }
+
+    /// Read cold-junction temperature, thermocouple temperature and fault status
+    /// in a single transaction, so all values belong to the same conversion.
+    /// No conversion is triggered.
+    pub fn read_all(&mut self) -> Result<Reading, Error> {
+        self.read_all_raw().map(Reading::from)
+    }
+
+    /// Read the register codes of cold-junction temperature, thermocouple temperature
+    /// and fault status in a single transaction, without any floating point math.
+    /// See read_all().
+    pub fn read_all_raw(&mut self) -> Result<RawReading, Error> {
+        // One byte address, two bytes of cj, three bytes of tc data and fault status
+        let mut buffer = [0u8; 7];
+        buffer[0] = Registers::CJTH.read_address;
+        self.read_registers(&mut buffer)?;
+        let mut registers = [0u8; 6];
+        registers.copy_from_slice(&buffer[1..]);
+        let reading = RawReading::from_registers(registers);
+        self.auto_clear(reading.faults)?;
+        Ok(reading)
     }
 
     /// Get the linearized and cold-junction-compensated thermocouple
-    /// temperature value.
-    pub fn temperature(&mut self) -> Result<f32, Error>{
+    /// temperature value. Returns InvalidArgument when a voltage mode is selected,
+    /// see read_voltage().
+    /// In normally off mode a one-shot conversion is triggered but not waited for,
+    /// so the value of the previous conversion is returned. Use read_one_shot() to
+    /// wait for the triggered conversion.
+    pub fn temperature(&mut self) -> Result<Temperature, Error>{
+        self.temperature_raw().map(linearized_code_to_temperature)
+    }
+
+    /// Get the linearized and cold-junction-compensated thermocouple temperature as
+    /// signed 19 bit code with a resolution of 0.0078125°C, without any floating point math.
+    /// See temperature().
+    pub fn temperature_raw(&mut self) -> Result<i32, Error> {
+        if self.config.type_selection.voltage_gain().is_some() {
+            return Err(Error::InvalidArgument);
+        }
         //If conversion mode is normally off, a one-time conversion should be done.
         //The one shot conversion takes about 150ms and then the bit is reset.
         //On automatic conversion mode, the temperature can requested without 1-shot trigger
 
         let cmode = self.config.conversion_mode;
-        match cmode {
-            CMode::NormallyOff => {
-                self.config.one_shot_conversion = OneShot::OneShotConversion;
-                self.send_c0()?; //One shot only changes c0. This part is executed often
-            }
-            _ => {}
-        } 
+        if let CMode::NormallyOff = cmode {
+            self.trigger_one_shot()?;
+        }
+        self.read_linearized_code()
+    }
+
+    /// Get the linearized and cold-junction-compensated thermocouple temperature
+    /// in millidegrees Celsius. See temperature().
+    pub fn temperature_millicelsius(&mut self) -> Result<i32, Error> {
+        self.temperature_raw().map(linearized_code_to_millicelsius)
+    }
+
+    /// Get the linearized and cold-junction-compensated thermocouple temperature
+    /// in °C as fixed point number. See temperature().
+    #[cfg(feature = "fixed")]
+    pub fn temperature_fixed(&mut self) -> Result<fixed::types::I16F16, Error> {
+        let code = self.temperature_raw()?;
+        // 7 fractional bits
+        Ok(fixed::types::I16F16::from_bits(code << 9))
+    }
+
+    /// Get the thermocouple input voltage in microvolts when a voltage mode
+    /// is selected as thermocouple type, otherwise InvalidArgument is returned.
+    /// In normally off mode a one-shot conversion is triggered and waited for,
+    /// see read_one_shot().
+    pub fn read_voltage<D: DelayNs>(&mut self, delay: &mut D) -> Result<f32, Error> {
+        let gain = self.config.type_selection.voltage_gain().ok_or(Error::InvalidArgument)?;
+        if let CMode::NormallyOff = self.config.conversion_mode {
+            self.trigger_one_shot()?;
+            delay.delay_us(self.config.one_shot_conversion_time_us());
+        }
+        let mut buffer = [0u8; 4]; // One byte address, three bytes of voltage data
+        buffer[0] = Registers::LTCBH.read_address;
+        self.read_registers(&mut buffer)?;
+        Ok(decode_voltage([buffer[1], buffer[2], buffer[3]], gain))
+    }
+
+    /// Read the input voltage and cold-junction temperature of the same conversion in
+    /// voltage mode and compensate and linearize them in software, e.g. with a
+    /// ThermocoupleType or a linearization for other thermocouples. See its90::compensate().
+    /// Returns InvalidArgument if no voltage mode is selected, if the thermocouple
+    /// type is a voltage mode or if the result is out of range of the linearization.
+    /// In normally off mode a one-shot conversion is triggered and waited for,
+    /// see read_one_shot().
+    pub fn read_compensated<L, D>(&mut self, linearization: &L, delay: &mut D) -> Result<CompensatedReading, Error>
+    where
+        L: crate::linearization::Linearization + ?Sized,
+        D: DelayNs,
+    {
+        let gain = self.config.type_selection.voltage_gain().ok_or(Error::InvalidArgument)?;
+        if let CMode::NormallyOff = self.config.conversion_mode {
+            self.trigger_one_shot()?;
+            delay.delay_us(self.config.one_shot_conversion_time_us());
+        }
+        // One byte address, two bytes of cj, three bytes of voltage data and fault status
+        let mut buffer = [0u8; 7];
+        buffer[0] = Registers::CJTH.read_address;
+        self.read_registers(&mut buffer)?;
+        let mut registers = [0u8; 6];
+        registers.copy_from_slice(&buffer[1..]);
+        let reading = CompensatedReading::from_registers(registers, gain, linearization)?;
+        self.auto_clear(reading.faults)?;
+        Ok(reading)
+    }
 
+    /// Read a software compensated reading with read_compensated() and, for comparison,
+    /// the linearized temperature of the chip for the same thermocouple type as
+    /// (reading, chip temperature). The chip temperature is read from a second one-shot
+    /// conversion with the thermocouple type written to C1. C1 is restored from the
+    /// stored configuration afterwards.
+    /// Only available in normally off mode, since a conversion in automatic mode could
+    /// mix both types. Returns InvalidArgument in automatic mode, if no voltage mode
+    /// is selected or if the thermocouple type is a voltage mode.
+    pub fn read_compensated_with_chip<D>(&mut self, thermocouple_type: ThermocoupleType, delay: &mut D) -> Result<(CompensatedReading, Temperature), Error>
+    where
+        D: DelayNs,
+    {
+        if self.config.conversion_mode == CMode::AutomaticConversion
+            || thermocouple_type.voltage_gain().is_some() {
+            return Err(Error::InvalidArgument);
+        }
+        let reading = self.read_compensated(&thermocouple_type, delay)?;
+        let mut options = self.config;
+        options.type_selection(thermocouple_type);
+        self.write_registers(&[Registers::CR1.write_address, options.extract_c1()])?;
+        self.trigger_one_shot()?;
+        delay.delay_us(self.config.one_shot_conversion_time_us());
+        let code = self.read_linearized_code();
+        // Restore the voltage mode before returning any read error
+        self.write_registers(&[Registers::CR1.write_address, self.config.extract_c1()])?;
+        Ok((reading, linearized_code_to_temperature(code?)))
+    }
+
+    /// Trigger a one-shot conversion, wait until it has completed and read the
+    /// linearized and cold-junction-compensated thermocouple temperature value.
+    /// The wait time is derived from the noise rejection filter and averaging mode.
+    /// Only available in normally off mode, otherwise InvalidArgument is returned.
+    pub fn read_one_shot<D: DelayNs>(&mut self, delay: &mut D) -> Result<Temperature, Error> {
+        if self.config.conversion_mode == CMode::AutomaticConversion
+            || self.config.type_selection.voltage_gain().is_some() {
+            return Err(Error::InvalidArgument);
+        }
+        self.trigger_one_shot()?;
+        delay.delay_us(self.config.one_shot_conversion_time_us());
+        self.read_linearized_temperature()
+    }
+
+    // Writes the one shot bit without changing the stored configuration.
+    // One shot only changes c0. This part is executed often
+    fn trigger_one_shot(&mut self) -> Result<(), Error> {
+        self.write_registers(&[Registers::CR0.write_address, self.config.extract_c0_one_shot()])
+    }
+
+    // Reads the first thermocouple result byte, which deasserts DRDY
+    fn clear_data_ready(&mut self) -> Result<(), Error> {
+        let mut buffer = [0u8; 2]; // One byte address and the LTCBH register value
+        buffer[0] = Registers::LTCBH.read_address;
+        self.read_registers(&mut buffer)
+    }
+
+    fn read_linearized_temperature(&mut self) -> Result<Temperature, Error> {
+        self.read_linearized_code().map(linearized_code_to_temperature)
+    }
+
+    fn read_linearized_code(&mut self) -> Result<i32, Error> {
         let mut buffer = [0u8; 4]; // One byte address, three bytes of temperature data
         buffer[0] = Registers::LTCBH.read_address;
-        self.spi.transfer_in_place(&mut buffer).map_err(|_| Error::Spi)?;
+        self.read_registers(&mut buffer)?;
         // TODO Check if any of the faults are triggered especially 
         // Check for over/under voltage or open circuit fault
+        Ok(decode_linearized_code([buffer[1], buffer[2], buffer[3]]))
+    }
 
-        // move bytes into int
-        let mut value: i32 = (buffer[1] as i32) << 16;
-        value += (buffer[2] as i32) << 8;
-        value += buffer[3] as i32;
-        // fill extra bits with msb for twos-complement representation with larger int
-        if buffer[1] & 0x80 == 0x80 {
-            value += 0xFF_i32 << 24;
+    /// Read and decode all bits of the fault status register.
+    /// Device faults are returned as data, Err is only returned on communication errors.
+    pub fn faults(&mut self) -> Result<DeviceErrors, Error> {
+        let mut buffer = [0u8; 2]; // One byte value from Fault status register
+        buffer[0] = Registers::SR.read_address;
+        self.read_registers(&mut buffer)?;
+        let faults = DeviceErrors::from_register(buffer[1]);
+        self.auto_clear(faults)?;
+        Ok(faults)
+    }
+
+    /// Clear the fault status register and deassert the FAULT output in interrupt
+    /// fault mode. The rest of C0 is written from the stored configuration
+    /// without triggering a one-shot conversion.
+    pub fn clear_faults(&mut self) -> Result<(), Error> {
+        self.write_registers(&[Registers::CR0.write_address, self.config.extract_c0_fault_clear()])
+    }
+
+    /// Clear faults automatically after they have been read and reported
+    /// by faults(), fault_status() or read_all() in interrupt fault mode
+    pub fn set_auto_clear_faults(&mut self, enable: bool) {
+        self.auto_clear_faults = enable;
+    }
+
+    fn auto_clear(&mut self, faults: DeviceErrors) -> Result<(), Error> {
+        if self.auto_clear_faults && faults.any() && self.config.fault_mode == FaultModes::Interrupt {
+            self.clear_faults()?;
         }
-        // shift out least significant 5 bits because they are not used
-        value >>= 5;
-        // multiply by measurement accuracy
-        Ok(value as f32 * 0.0078125)
+        Ok(())
+    }
+
+    // Reads registers starting at the address in the first byte, the values are
+    // returned in the remaining bytes
+    fn read_registers(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+        let address = buffer[0];
+        self.spi.transfer_in_place(buffer).map_err(Error::spi(address))?;
+        trace!("read register 0x{=u8:02X}: {=[u8]:02X}", address, &buffer[1..]);
+        Ok(())
+    }
+
+    // Writes the remaining bytes to the registers starting at the write address
+    // in the first byte. Errors report the read address like the other accesses
+    fn write_registers(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let address = bytes[0] & 0x7F;
+        trace!("write register 0x{=u8:02X}: {=[u8]:02X}", address, &bytes[1..]);
+        self.spi.write(bytes).map_err(Error::spi(address))
     }
 
-    /// Check if any of the faults are triggered
+    /// Check if any of the faults are triggered.
+    /// Device faults are returned as Error::Device and only the overvoltage or
+    /// undervoltage fault is reported if it is present. Use faults() to get all bits.
     pub fn fault_status(&mut self) -> Result<(), Error>{
         let mut buffer = [0u8; 2]; // One byte value from Fault status register
         buffer[0] = Registers::SR.read_address;
-        self.spi.transfer_in_place(&mut buffer).map_err(|_| Error::Spi)?;
-        let error_id = buffer[1];
-        let mut has_error = false;
-        let mut errors =  DeviceErrors::default();
-
-        //If overvoltage or undervoltage, all other errors might not be set
-        if(error_id & FaultBits::OVUV) !=0 {
-            errors.overvoltage_undervoltage = true;
-            return Err(Error::Device(errors))
-        }
-        if(error_id & FaultBits::CJ_HIGH) !=0 {
-            errors.cold_junction_high = true;
-            has_error = true;
-        }
-        if(error_id & FaultBits::CJ_LOW) !=0 {
-            errors.cold_junction_low = true;
-            has_error = true;
-        }
-        if(error_id & FaultBits::CJ_RANGE) !=0 {
-            errors.cold_junction_out_of_range = true;
-            has_error = true;
-        }
-        if(error_id & FaultBits::OPEN) !=0 {
-            errors.open_circuit = true;
-            has_error = true;
-        }
-        if(error_id & FaultBits::TC_HIGH) !=0 {
-            errors.thermocouple_high = true;
-            has_error = true;
-        }
-        if(error_id & FaultBits::TC_LOW) !=0 {
-            errors.thermocouple_low = true;
-            has_error = true;
-        }
-        if(error_id & FaultBits::TC_RANGE) !=0 {
-            errors.thermocouple_out_of_range = true;
-            has_error = true;
-        }
-        if has_error {            
-            Err(Error::Device(errors))
-        } else {
-            Ok(())
-        }
+        self.read_registers(&mut buffer)?;
+        self.auto_clear(DeviceErrors::from_register(buffer[1]))?;
+        decode_fault_status(buffer[1])
     }
 }
 
 #[cfg(test)]
 mod internal {
     use super::*;
+    use core::convert::TryFrom;
     #[test]
     fn can_extract_max31856_c0_c1() {
         let mut options = Max31856Options::new();
@@ -246,4 +1003,19 @@ mod internal {
         let c0_c1 = (options.extract_c0(), options.extract_c1());
         assert_eq!(c0_c1, (0b1000_0101, 0b0100_0011));
     }
+
+    #[test]
+    fn can_decode_max31856_c0_c1() {
+        let mut options = Max31856Options::new();
+        options.average_samples(AveragingMode::EightSamples)
+            .open_circuit_fault_det(OCFaultModes::Enabled40k2ms)
+            .cold_junction_sensor_disable(true)
+            .type_selection(ThermocoupleType::TType);
+        let decoded = Max31856Options::from_registers(options.extract_c0(), options.extract_c1());
+        assert_eq!(decoded.unwrap(), options);
+        assert!(matches!(AveragingMode::try_from(8), Err(Error::InvalidArgument)));
+        options.type_selection(ThermocoupleType::VModeGain32Type);
+        let decoded = Max31856Options::from_registers(options.extract_c0(), options.extract_c1());
+        assert_eq!(decoded.unwrap().type_selection, ThermocoupleType::VModeGain32Type);
+    }
 }
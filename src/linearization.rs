@@ -0,0 +1,193 @@
+//! Linearization of thermocouple voltages in software.
+//!
+//! Used with voltage mode conversions for thermocouples that are not supported by the
+//! MAX31856, e.g. tungsten-rhenium thermocouples in furnaces. See
+//! [`Max31856::read_compensated()`](crate::Max31856::read_compensated).
+//!
+//! A [`Linearization`] converts between temperature in °C and thermoelectric voltage
+//! (EMF) in mV with the reference junction at 0 °C. It is implemented for
+//! - [`ThermocoupleType`] with the NIST ITS-90 functions, see [`crate::its90`]
+//! - [`Polynomials`] from user-supplied coefficients
+//! - [`Table`] from user-supplied EMF/temperature points
+//!
+//! Built in is [`TYPE_C`] from the ASTM E988 reference function. Types D and G can be
+//! supplied as a [`Polynomials`] from the ASTM E988 reference functions, and types L
+//! and U as a [`Table`] from the DIN 43710 tables.
+
+use crate::{Error, ThermocoupleType};
+
+/// Conversion between temperature and thermoelectric voltage of a thermocouple
+pub trait Linearization {
+    /// Thermoelectric voltage in mV at the given temperature in °C,
+    /// with the reference junction at 0 °C.
+    /// Returns InvalidArgument outside of the valid range.
+    fn temperature_to_emf(&self, celsius: f64) -> Result<f64, Error>;
+
+    /// Temperature in °C with the given thermoelectric voltage in mV,
+    /// with the reference junction at 0 °C.
+    /// Returns InvalidArgument outside of the valid range.
+    fn emf_to_temperature(&self, millivolts: f64) -> Result<f64, Error>;
+
+    /// Hot junction temperature in °C with the given thermoelectric voltage in mV
+    /// and the reference junction at the given cold-junction temperature in °C
+    fn compensate(&self, millivolts: f64, cold_junction: f64) -> Result<f64, Error> {
+        let cold_junction_emf = self.temperature_to_emf(cold_junction)?;
+        self.emf_to_temperature(millivolts + cold_junction_emf)
+    }
+}
+
+impl Linearization for ThermocoupleType {
+    fn temperature_to_emf(&self, celsius: f64) -> Result<f64, Error> {
+        crate::its90::temperature_to_emf(*self, celsius)
+    }
+
+    fn emf_to_temperature(&self, millivolts: f64) -> Result<f64, Error> {
+        crate::its90::emf_to_temperature(*self, millivolts)
+    }
+}
+
+/// Polynomial `sum(coefficients[i] * x^i)` valid from lower to upper, both inclusive
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Polynomial<'a> {
+    /// Lowest valid argument
+    pub lower: f64,
+    /// Highest valid argument
+    pub upper: f64,
+    /// Coefficients, starting with the constant term
+    pub coefficients: &'a [f64],
+}
+
+/// Piecewise polynomial reference function of a thermocouple.
+/// Without inverse polynomials the temperature is found by bisection, which
+/// requires the EMF to rise with temperature.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Polynomials<'a> {
+    temperature_to_emf: &'a [Polynomial<'a>],
+    emf_to_temperature: Option<&'a [Polynomial<'a>]>,
+}
+
+impl<'a> Polynomials<'a> {
+    /// Create from temperature in °C to EMF in mV polynomials, ordered by temperature.
+    /// Returns InvalidArgument if there are no polynomials or their ranges
+    /// are not contiguous.
+    pub fn new(temperature_to_emf: &'a [Polynomial<'a>]) -> Result<Self, Error> {
+        check_contiguous(temperature_to_emf)?;
+        Ok(Polynomials { temperature_to_emf, emf_to_temperature: None })
+    }
+
+    /// Add EMF in mV to temperature in °C polynomials, ordered by EMF.
+    /// Returns InvalidArgument if there are no polynomials or their ranges
+    /// are not contiguous.
+    pub fn with_inverse(self, emf_to_temperature: &'a [Polynomial<'a>]) -> Result<Self, Error> {
+        check_contiguous(emf_to_temperature)?;
+        Ok(Polynomials { emf_to_temperature: Some(emf_to_temperature), ..self })
+    }
+}
+
+impl Linearization for Polynomials<'_> {
+    fn temperature_to_emf(&self, celsius: f64) -> Result<f64, Error> {
+        evaluate(self.temperature_to_emf, celsius)
+    }
+
+    fn emf_to_temperature(&self, millivolts: f64) -> Result<f64, Error> {
+        if let Some(inverse) = self.emf_to_temperature {
+            return evaluate(inverse, millivolts);
+        }
+        let (mut low, mut high) = limits(self.temperature_to_emf);
+        if !(millivolts >= self.temperature_to_emf(low)? && millivolts <= self.temperature_to_emf(high)?) {
+            return Err(Error::InvalidArgument);
+        }
+        // 64 halvings are more than the resolution of f64
+        for _ in 0..64 {
+            let middle = (low + high) / 2.0;
+            if self.temperature_to_emf(middle)? < millivolts {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        Ok((low + high) / 2.0)
+    }
+}
+
+/// Lookup table of (temperature in °C, EMF in mV) points, interpolated linearly
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Table<'a> {
+    points: &'a [(f64, f64)],
+}
+
+impl<'a> Table<'a> {
+    /// Create from (temperature in °C, EMF in mV) points.
+    /// Returns InvalidArgument if there are less than two points or temperature
+    /// and EMF are not both strictly increasing.
+    pub fn new(points: &'a [(f64, f64)]) -> Result<Self, Error> {
+        let increasing = points.windows(2).all(|pair| pair[0].0 < pair[1].0 && pair[0].1 < pair[1].1);
+        if points.len() < 2 || !increasing {
+            return Err(Error::InvalidArgument);
+        }
+        Ok(Table { points })
+    }
+
+    /// Get the (temperature in °C, EMF in mV) points
+    pub fn points(&self) -> &'a [(f64, f64)] {
+        self.points
+    }
+}
+
+impl Linearization for Table<'_> {
+    fn temperature_to_emf(&self, celsius: f64) -> Result<f64, Error> {
+        interpolate(self.points.iter().copied(), celsius)
+    }
+
+    fn emf_to_temperature(&self, millivolts: f64) -> Result<f64, Error> {
+        interpolate(self.points.iter().map(|&(celsius, emf)| (emf, celsius)), millivolts)
+    }
+}
+
+/// Type C (W-5%Re / W-26%Re), 0 °C to 2315 °C, ASTM E988 reference function.
+/// The temperature is found by bisection.
+pub const TYPE_C: Polynomials<'static> = Polynomials {
+    temperature_to_emf: &[
+        Polynomial { lower: 0.0, upper: 630.615, coefficients: &[
+            0.0, 1.3406032E-02, 1.1924992E-05, -7.9806354E-09, -5.0787515E-12,
+            1.3164197E-14, -7.9197332E-18,
+        ] },
+        Polynomial { lower: 630.615, upper: 2315.0, coefficients: &[
+            4.0528823E-01, 1.1509355E-02, 1.5696453E-05, -1.3704412E-08, 5.2290873E-12,
+            -9.2082758E-16, 4.5245112E-20,
+        ] },
+    ],
+    emf_to_temperature: None,
+};
+
+// Evaluates the polynomial whose range contains x
+pub(crate) fn evaluate(polynomials: &[Polynomial], x: f64) -> Result<f64, Error> {
+    let polynomial = polynomials.iter()
+        .find(|polynomial| x >= polynomial.lower && x <= polynomial.upper)
+        .ok_or(Error::InvalidArgument)?;
+    Ok(polynomial.coefficients.iter().rev().fold(0.0, |sum, c| sum * x + c))
+}
+
+pub(crate) fn limits(polynomials: &[Polynomial]) -> (f64, f64) {
+    (polynomials[0].lower, polynomials[polynomials.len() - 1].upper)
+}
+
+fn check_contiguous(polynomials: &[Polynomial]) -> Result<(), Error> {
+    let contiguous = polynomials.windows(2).all(|pair| pair[0].upper == pair[1].lower);
+    let ordered = polynomials.iter().all(|polynomial| polynomial.lower < polynomial.upper);
+    if polynomials.is_empty() || !contiguous || !ordered {
+        return Err(Error::InvalidArgument);
+    }
+    Ok(())
+}
+
+// Interpolates linearly between the (x, y) points with increasing x
+fn interpolate<I: Iterator<Item = (f64, f64)> + Clone>(points: I, x: f64) -> Result<f64, Error> {
+    points.clone().zip(points.skip(1))
+        .find(|&((x0, _), (x1, _))| x >= x0 && x <= x1)
+        .map(|((x0, y0), (x1, y1))| y0 + (x - x0) * (y1 - y0) / (x1 - x0))
+        .ok_or(Error::InvalidArgument)
+}
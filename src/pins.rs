@@ -0,0 +1,72 @@
+use crate::Error;
+use hal::digital::{Error as _, ErrorKind};
+
+/// Marker for a pin that is not connected
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NoPin;
+
+/// Output pins of the sensor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PinId {
+    /// FAULT output
+    Fault,
+    /// DRDY output
+    DataReady,
+}
+
+/// FAULT pin split off from the driver, e.g. to be handed to an interrupt handler
+/// while the SPI half stays with the main loop.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FaultPin<FP> {
+    pin: FP,
+}
+
+impl<FP: hal::digital::InputPin> FaultPin<FP> {
+    pub(crate) fn new(pin: FP) -> Self {
+        FaultPin { pin }
+    }
+
+    /// Check if the FAULT output is asserted
+    pub fn is_asserted(&mut self) -> Result<bool, Error> {
+        self.pin.is_low().map_err(Error::pin(PinId::Fault))
+    }
+
+    /// Get the underlying pin, e.g. to configure or clear its interrupt
+    pub fn pin(&mut self) -> &mut FP {
+        &mut self.pin
+    }
+
+    /// Release the underlying pin
+    pub fn release(self) -> FP {
+        self.pin
+    }
+}
+
+/// An active low output pin of the sensor that may or may not be connected.
+/// Implemented for NoPin and every InputPin.
+pub trait OptionalPin: private::Sealed {
+    /// Check if the pin is asserted. Returns None if the pin is not connected.
+    fn is_asserted(&mut self) -> Result<Option<bool>, ErrorKind>;
+}
+
+impl OptionalPin for NoPin {
+    fn is_asserted(&mut self) -> Result<Option<bool>, ErrorKind> {
+        Ok(None)
+    }
+}
+
+impl<P: hal::digital::InputPin> OptionalPin for P {
+    fn is_asserted(&mut self) -> Result<Option<bool>, ErrorKind> {
+        self.is_low().map(Some).map_err(|error| error.kind())
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::NoPin {}
+    impl<P: hal::digital::InputPin> Sealed for P {}
+}
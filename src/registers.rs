@@ -63,8 +63,16 @@ impl Registers {
         write_address: 0x89,
         factory_default: 0x00,
     }; //Cold Junction Temperature offset
-    pub const CJTH: ReadOnlyRegister = ReadOnlyRegister { read_address: 0x0A }; //Cold Junction Temperature High
-    pub const CJTL: ReadOnlyRegister = ReadOnlyRegister { read_address: 0x0B }; //Cold Junction Temperature Low
+    pub const CJTH: ReadWriteRegister = ReadWriteRegister {
+        read_address: 0x0A,
+        write_address: 0x8A,
+        factory_default: 0x00,
+    }; //Cold Junction Temperature High. Writable when the cold-junction sensor is disabled
+    pub const CJTL: ReadWriteRegister = ReadWriteRegister {
+        read_address: 0x0B,
+        write_address: 0x8B,
+        factory_default: 0x00,
+    }; //Cold Junction Temperature Low. Writable when the cold-junction sensor is disabled
     pub const LTCBH: ReadOnlyRegister = ReadOnlyRegister { read_address: 0x0C };
     pub const LTCBM: ReadOnlyRegister = ReadOnlyRegister { read_address: 0x0D };
     pub const LTCBL: ReadOnlyRegister = ReadOnlyRegister { read_address: 0x0E };
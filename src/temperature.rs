@@ -0,0 +1,180 @@
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Absolute temperature, e.g. a reading or a fault threshold.
+/// Stored in °C, which is the unit of the sensor.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Temperature {
+    celsius: f32,
+}
+
+/// Difference between two temperatures, e.g. the cold-junction offset.
+/// A difference of 1°C is 1K or 1.8°F.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TemperatureDelta {
+    celsius: f32,
+}
+
+impl Temperature {
+    /// Create from degrees Celsius
+    pub const fn from_celsius(celsius: f32) -> Self {
+        Temperature { celsius }
+    }
+
+    /// Create from degrees Fahrenheit
+    pub fn from_fahrenheit(fahrenheit: f32) -> Self {
+        Temperature { celsius: (fahrenheit - 32.0) / 1.8 }
+    }
+
+    /// Create from kelvin
+    pub fn from_kelvin(kelvin: f32) -> Self {
+        Temperature { celsius: kelvin - 273.15 }
+    }
+
+    /// Get in degrees Celsius
+    pub fn celsius(self) -> f32 {
+        self.celsius
+    }
+
+    /// Get in degrees Fahrenheit
+    pub fn fahrenheit(self) -> f32 {
+        self.celsius * 1.8 + 32.0
+    }
+
+    /// Get in kelvin
+    pub fn kelvin(self) -> f32 {
+        self.celsius + 273.15
+    }
+}
+
+impl TemperatureDelta {
+    /// Create from degrees Celsius
+    pub const fn from_celsius(celsius: f32) -> Self {
+        TemperatureDelta { celsius }
+    }
+
+    /// Create from degrees Fahrenheit
+    pub fn from_fahrenheit(fahrenheit: f32) -> Self {
+        TemperatureDelta { celsius: fahrenheit / 1.8 }
+    }
+
+    /// Create from kelvin
+    pub const fn from_kelvin(kelvin: f32) -> Self {
+        TemperatureDelta { celsius: kelvin }
+    }
+
+    /// Get in degrees Celsius
+    pub fn celsius(self) -> f32 {
+        self.celsius
+    }
+
+    /// Get in degrees Fahrenheit
+    pub fn fahrenheit(self) -> f32 {
+        self.celsius * 1.8
+    }
+
+    /// Get in kelvin
+    pub fn kelvin(self) -> f32 {
+        self.celsius
+    }
+}
+
+impl Add<TemperatureDelta> for Temperature {
+    type Output = Temperature;
+
+    fn add(self, delta: TemperatureDelta) -> Temperature {
+        Temperature { celsius: self.celsius + delta.celsius }
+    }
+}
+
+impl Sub<TemperatureDelta> for Temperature {
+    type Output = Temperature;
+
+    fn sub(self, delta: TemperatureDelta) -> Temperature {
+        Temperature { celsius: self.celsius - delta.celsius }
+    }
+}
+
+impl AddAssign<TemperatureDelta> for Temperature {
+    fn add_assign(&mut self, delta: TemperatureDelta) {
+        self.celsius += delta.celsius;
+    }
+}
+
+impl SubAssign<TemperatureDelta> for Temperature {
+    fn sub_assign(&mut self, delta: TemperatureDelta) {
+        self.celsius -= delta.celsius;
+    }
+}
+
+impl Sub for Temperature {
+    type Output = TemperatureDelta;
+
+    fn sub(self, other: Temperature) -> TemperatureDelta {
+        TemperatureDelta { celsius: self.celsius - other.celsius }
+    }
+}
+
+impl Add for TemperatureDelta {
+    type Output = TemperatureDelta;
+
+    fn add(self, other: TemperatureDelta) -> TemperatureDelta {
+        TemperatureDelta { celsius: self.celsius + other.celsius }
+    }
+}
+
+impl Sub for TemperatureDelta {
+    type Output = TemperatureDelta;
+
+    fn sub(self, other: TemperatureDelta) -> TemperatureDelta {
+        TemperatureDelta { celsius: self.celsius - other.celsius }
+    }
+}
+
+impl Mul<f32> for TemperatureDelta {
+    type Output = TemperatureDelta;
+
+    fn mul(self, factor: f32) -> TemperatureDelta {
+        TemperatureDelta { celsius: self.celsius * factor }
+    }
+}
+
+impl Neg for TemperatureDelta {
+    type Output = TemperatureDelta;
+
+    fn neg(self) -> TemperatureDelta {
+        TemperatureDelta { celsius: -self.celsius }
+    }
+}
+
+#[cfg(feature = "uom")]
+mod uom_conversions {
+    use super::{Temperature, TemperatureDelta};
+    use uom::si::f32::{TemperatureInterval, ThermodynamicTemperature};
+    use uom::si::{temperature_interval, thermodynamic_temperature};
+
+    impl From<Temperature> for ThermodynamicTemperature {
+        fn from(temperature: Temperature) -> Self {
+            ThermodynamicTemperature::new::<thermodynamic_temperature::degree_celsius>(temperature.celsius)
+        }
+    }
+
+    impl From<ThermodynamicTemperature> for Temperature {
+        fn from(temperature: ThermodynamicTemperature) -> Self {
+            Temperature::from_celsius(temperature.get::<thermodynamic_temperature::degree_celsius>())
+        }
+    }
+
+    impl From<TemperatureDelta> for TemperatureInterval {
+        fn from(delta: TemperatureDelta) -> Self {
+            TemperatureInterval::new::<temperature_interval::degree_celsius>(delta.celsius)
+        }
+    }
+
+    impl From<TemperatureInterval> for TemperatureDelta {
+        fn from(delta: TemperatureInterval) -> Self {
+            TemperatureDelta::from_celsius(delta.get::<temperature_interval::degree_celsius>())
+        }
+    }
+}
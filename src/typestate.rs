@@ -0,0 +1,122 @@
+//! Driver with the conversion mode tracked in its type.
+//!
+//! The noise rejection filter and averaging mode must only be changed in normally off
+//! mode. Here these changes are only available on [`Max31856<_, _, NormallyOff>`](Max31856),
+//! and switching modes consumes the driver.
+//! Fault masks, thresholds and offsets can be set up on the wrapped
+//! [`crate::Max31856`] before it is passed to [`Max31856::new()`].
+
+use core::marker::PhantomData;
+
+use crate::{AveragingMode, CMode, DeviceErrors, Error, NoPin, NoiseRejectionMode, Temperature, ThermocoupleType};
+use hal::delay::DelayNs;
+
+/// Normally off mode. Conversions are only done on request.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NormallyOff;
+
+/// Automatic conversion mode. Conversions occur continuously every 100ms (nominal).
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Automatic;
+
+/// Max31856 driver in conversion mode MODE
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Max31856<SPI, FP, MODE, DP = NoPin> {
+    driver: crate::Max31856<SPI, FP, DP>,
+    mode: PhantomData<MODE>,
+}
+
+impl<SPI, FP, DP> Max31856<SPI, FP, NormallyOff, DP>
+where
+    SPI: hal::spi::SpiDevice,
+{
+    /// Put the driver in normally off mode and write its configuration to the sensor
+    pub fn new(mut driver: crate::Max31856<SPI, FP, DP>) -> Result<Self, Error> {
+        driver.config().conversion_mode(CMode::NormallyOff);
+        driver.send_config()?;
+        Ok(Max31856 { driver, mode: PhantomData })
+    }
+
+    /// Switch to automatic conversion mode
+    pub fn into_automatic(mut self) -> Result<Max31856<SPI, FP, Automatic, DP>, Error> {
+        self.driver.config().conversion_mode(CMode::AutomaticConversion);
+        self.driver.send_config()?;
+        Ok(Max31856 { driver: self.driver, mode: PhantomData })
+    }
+
+    /// Set and write the noise rejection filter
+    pub fn set_noise_rejection_frequency(&mut self, mode: NoiseRejectionMode) -> Result<(), Error> {
+        self.driver.config().noise_rejection_frequency(mode);
+        self.driver.send_config()
+    }
+
+    /// Set and write the averaging mode
+    pub fn set_average_samples(&mut self, mode: AveragingMode) -> Result<(), Error> {
+        self.driver.config().average_samples(mode);
+        self.driver.send_config()
+    }
+
+    /// Set and write the thermocouple type
+    pub fn set_type_selection(&mut self, mode: ThermocoupleType) -> Result<(), Error> {
+        self.driver.config().type_selection(mode);
+        self.driver.send_config()
+    }
+
+    /// Trigger a one-shot conversion, wait until it has completed and read the
+    /// linearized and cold-junction-compensated thermocouple temperature value.
+    pub fn read_one_shot<D: DelayNs>(&mut self, delay: &mut D) -> Result<Temperature, Error> {
+        self.driver.read_one_shot(delay)
+    }
+}
+
+impl<SPI, FP, DP> Max31856<SPI, FP, Automatic, DP>
+where
+    SPI: hal::spi::SpiDevice,
+{
+    /// Switch to normally off mode
+    pub fn into_normally_off(mut self) -> Result<Max31856<SPI, FP, NormallyOff, DP>, Error> {
+        self.driver.config().conversion_mode(CMode::NormallyOff);
+        self.driver.send_config()?;
+        Ok(Max31856 { driver: self.driver, mode: PhantomData })
+    }
+
+    /// Get the linearized and cold-junction-compensated thermocouple
+    /// temperature value of the latest conversion.
+    pub fn temperature(&mut self) -> Result<Temperature, Error> {
+        self.driver.temperature()
+    }
+}
+
+impl<SPI, FP, MODE, DP> Max31856<SPI, FP, MODE, DP>
+where
+    SPI: hal::spi::SpiDevice,
+{
+    /// Get the measured value of cold-junction temperature
+    /// plus the value in the Cold-Junction Offset register
+    pub fn cold_junction_temperature(&mut self) -> Result<Temperature, Error> {
+        self.driver.cold_junction_temperature()
+    }
+
+    /// Read and decode all bits of the fault status register
+    pub fn faults(&mut self) -> Result<DeviceErrors, Error> {
+        self.driver.faults()
+    }
+
+    /// Check if any of the faults are triggered
+    pub fn fault_status(&mut self) -> Result<(), Error> {
+        self.driver.fault_status()
+    }
+
+    /// Clear the fault status register in interrupt fault mode
+    pub fn clear_faults(&mut self) -> Result<(), Error> {
+        self.driver.clear_faults()
+    }
+
+    /// Release the wrapped driver. Its configuration reflects the current mode.
+    pub fn release(self) -> crate::Max31856<SPI, FP, DP> {
+        self.driver
+    }
+}
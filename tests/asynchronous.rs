@@ -0,0 +1,149 @@
+#![cfg(feature = "async")]
+
+use embedded_hal_mock as hal;
+use self::hal::eh1::spi::{Mock as SpiMock,
+    Transaction as SpiTransaction};
+use self::hal::eh1::digital::{Mock as PinMock, State as PinState,
+    Transaction as PinTransaction};
+use self::hal::eh1::delay::{CheckedDelay, Transaction as DelayTransaction};
+use futures::executor::block_on;
+use max31856::Max31856Async;
+
+#[test]
+fn can_send_configuration() {
+    // SPI transactions
+    let spi_expectations = [
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0]), //Write C0
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x81, 0x23]), //Write C1
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut sensor = Max31856Async::new(&mut spi, &mut fault);
+    sensor.config().average_samples(max31856::AveragingMode::FourSamples);
+    block_on(sensor.send_config()).unwrap();
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_read_one_shot() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write oneshot c0
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x40]),
+        SpiTransaction::transaction_end(),
+        // Read temperature register with a value of 25.0 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0,0,0], vec![0x0C, 0x01, 0x90, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+    let delay_expectations = [DelayTransaction::async_delay_us(155_000)];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut delay = CheckedDelay::new(&delay_expectations);
+    let mut sensor = Max31856Async::new(&mut spi, &mut fault);
+    assert_eq!(block_on(sensor.read_one_shot(&mut delay)).unwrap().celsius(), 25.0);
+    spi.done();
+    fault.done();
+    delay.done();
+}
+
+#[test]
+fn can_read_when_data_ready() {
+    // SPI transactions
+    let spi_expectations = [
+        // Read temperature register with a value of -250.0 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0,0,0], vec![0x0C, 0xF0, 0x60, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+    let drdy_expectations = [PinTransaction::wait_for_state(PinState::Low)];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut drdy = PinMock::new(&drdy_expectations);
+    let mut sensor = Max31856Async::new(&mut spi, &mut fault).with_drdy_pin(&mut drdy);
+    sensor.config().conversion_mode(max31856::CMode::AutomaticConversion);
+    let mut delay = CheckedDelay::new(&[]);
+    assert_eq!(block_on(sensor.read_when_ready(&mut delay)).unwrap().celsius(), -250.0);
+    spi.done();
+    fault.done();
+    drdy.done();
+    delay.done();
+}
+
+// DRDY pin that never asserts, like during an overvoltage fault
+struct StuckPin;
+
+impl embedded_hal::digital::ErrorType for StuckPin {
+    type Error = core::convert::Infallible;
+}
+
+impl embedded_hal_async::digital::Wait for StuckPin {
+    async fn wait_for_high(&mut self) -> Result<(), Self::Error> { core::future::pending().await }
+    async fn wait_for_low(&mut self) -> Result<(), Self::Error> { core::future::pending().await }
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> { core::future::pending().await }
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> { core::future::pending().await }
+    async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> { core::future::pending().await }
+}
+
+#[test]
+fn can_time_out_when_data_is_not_ready() {
+    // SPI transactions
+    let spi_expectations = [
+        // Read fault status register with an overvoltage fault
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0F, 0], vec![0x0F, 0x02]),
+        SpiTransaction::transaction_end(),
+        // Read fault status register without faults
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0F, 0], vec![0x0F, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+    let delay_expectations = [
+        DelayTransaction::async_delay_us(155_000),
+        DelayTransaction::async_delay_us(155_000),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut delay = CheckedDelay::new(&delay_expectations);
+    let mut sensor = Max31856Async::new(&mut spi, &mut fault).with_drdy_pin(StuckPin);
+    sensor.config().conversion_mode(max31856::CMode::AutomaticConversion);
+    match block_on(sensor.read_when_ready(&mut delay)) {
+        Err(max31856::Error::Device(faults)) => assert!(faults.overvoltage_undervoltage),
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert!(matches!(block_on(sensor.read_when_ready(&mut delay)), Err(max31856::Error::Timeout)));
+    spi.done();
+    fault.done();
+    delay.done();
+}
+
+#[test]
+fn can_wait_for_fault() {
+    // SPI transactions
+    let spi_expectations = [
+        // Read fault status register with overvoltage and open circuit faults
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0F, 0], vec![0x0F, 0x03]),
+        SpiTransaction::transaction_end(),
+    ];
+    let fault_expectations = [PinTransaction::wait_for_state(PinState::Low)];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&fault_expectations);
+    let mut sensor = Max31856Async::new(&mut spi, &mut fault);
+    let faults = block_on(sensor.wait_for_fault()).unwrap();
+    assert!(faults.overvoltage_undervoltage);
+    assert!(faults.open_circuit);
+    spi.done();
+    fault.done();
+}
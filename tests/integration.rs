@@ -2,8 +2,10 @@ use max31856;
 use embedded_hal_mock as hal;
 use self::hal::eh1::spi::{Mock as SpiMock, 
     Transaction as SpiTransaction};
-use self::hal::eh1::digital::Mock as PinMock;
-use self::max31856::{Max31856, Error};
+use self::hal::eh1::digital::{Mock as PinMock, State as PinState,
+    Transaction as PinTransaction};
+use self::hal::eh1::delay::{CheckedDelay, Transaction as DelayTransaction};
+use self::max31856::{Max31856, Error, Temperature, TemperatureDelta};
 
 #[test]
 fn can_create_max31856_options() {
@@ -49,12 +51,13 @@ fn can_read_temperature_normally_off() {
     let mut spi = SpiMock::new(&spi_expectations);
     let mut fault = PinMock::new(&[]);
     let mut sensor = Max31856::new(&mut spi, &mut fault);
-    assert_eq!(sensor.temperature().unwrap(), 87.171875);
+    assert_eq!(sensor.temperature().unwrap().celsius(), 87.171875);
     spi.done();
     fault.done();
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn can_get_fault_status(){
     // SPI transactions
     let spi_expectations = [
@@ -173,16 +176,16 @@ fn can_read_temperatures_normally_off() {
     let mut spi = SpiMock::new(&spi_expectations);
     let mut fault = PinMock::new(&[]);
     let mut sensor = Max31856::new(&mut spi, &mut fault);
-    assert_eq!(sensor.temperature().unwrap(), 1600.0);
-    assert_eq!(sensor.temperature().unwrap(), 1000.0);
-    assert_eq!(sensor.temperature().unwrap(),  100.9375);
-    assert_eq!(sensor.temperature().unwrap(),   25.0);
-    assert_eq!(sensor.temperature().unwrap(),    0.0625);
-    assert_eq!(sensor.temperature().unwrap(),    0.0);
-    assert_eq!(sensor.temperature().unwrap(),   -0.0625);
-    assert_eq!(sensor.temperature().unwrap(),   -0.25);
-    assert_eq!(sensor.temperature().unwrap(),   -1.0);
-    assert_eq!(sensor.temperature().unwrap(), -250.0);
+    assert_eq!(sensor.temperature().unwrap().celsius(), 1600.0);
+    assert_eq!(sensor.temperature().unwrap().celsius(), 1000.0);
+    assert_eq!(sensor.temperature().unwrap().celsius(),  100.9375);
+    assert_eq!(sensor.temperature().unwrap().celsius(),   25.0);
+    assert_eq!(sensor.temperature().unwrap().celsius(),    0.0625);
+    assert_eq!(sensor.temperature().unwrap().celsius(),    0.0);
+    assert_eq!(sensor.temperature().unwrap().celsius(),   -0.0625);
+    assert_eq!(sensor.temperature().unwrap().celsius(),   -0.25);
+    assert_eq!(sensor.temperature().unwrap().celsius(),   -1.0);
+    assert_eq!(sensor.temperature().unwrap().celsius(), -250.0);
     spi.done();
     fault.done();
 }
@@ -240,17 +243,1031 @@ fn can_read_cold_junction_temperature() {
     let mut spi = SpiMock::new(&spi_expectations);
     let mut fault = PinMock::new(&[]);
     let mut sensor = Max31856::new(&mut spi, &mut fault);
-    assert_eq!(sensor.cold_junction_temperature().unwrap(), 127.984375);
-    assert_eq!(sensor.cold_junction_temperature().unwrap(), 127.0);
-    assert_eq!(sensor.cold_junction_temperature().unwrap(), 125.0);
-    assert_eq!(sensor.cold_junction_temperature().unwrap(),  64.0);
-    assert_eq!(sensor.cold_junction_temperature().unwrap(),  25.0);
-    assert_eq!(sensor.cold_junction_temperature().unwrap(),   0.5);
-    assert_eq!(sensor.cold_junction_temperature().unwrap(),   0.015625);
-    assert_eq!(sensor.cold_junction_temperature().unwrap(),   0.0);
-    assert_eq!(sensor.cold_junction_temperature().unwrap(),  -0.5);
-    assert_eq!(sensor.cold_junction_temperature().unwrap(), -25.0);
-    assert_eq!(sensor.cold_junction_temperature().unwrap(), -55.0);
+    assert_eq!(sensor.cold_junction_temperature().unwrap().celsius(), 127.984375);
+    assert_eq!(sensor.cold_junction_temperature().unwrap().celsius(), 127.0);
+    assert_eq!(sensor.cold_junction_temperature().unwrap().celsius(), 125.0);
+    assert_eq!(sensor.cold_junction_temperature().unwrap().celsius(),  64.0);
+    assert_eq!(sensor.cold_junction_temperature().unwrap().celsius(),  25.0);
+    assert_eq!(sensor.cold_junction_temperature().unwrap().celsius(),   0.5);
+    assert_eq!(sensor.cold_junction_temperature().unwrap().celsius(),   0.015625);
+    assert_eq!(sensor.cold_junction_temperature().unwrap().celsius(),   0.0);
+    assert_eq!(sensor.cold_junction_temperature().unwrap().celsius(),  -0.5);
+    assert_eq!(sensor.cold_junction_temperature().unwrap().celsius(), -25.0);
+    assert_eq!(sensor.cold_junction_temperature().unwrap().celsius(), -55.0);
     spi.done();
     fault.done();
 }
+
+#[test]
+fn can_write_and_read_fault_mask() {
+    // SPI transactions
+    let spi_expectations = [
+        // Unmask open circuit and thermocouple high faults
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x82, 0xF6]),
+        SpiTransaction::transaction_end(),
+        // Read fault mask register
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x02, 0], vec![0x02, 0xF6]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    let mask = max31856::FaultMask {
+        thermocouple_high: false,
+        open_circuit: false,
+        ..Default::default()
+    };
+    sensor.set_fault_mask(mask).unwrap();
+    assert_eq!(sensor.fault_mask().unwrap(), mask);
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_write_and_read_cold_junction_thresholds() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write high threshold of 70 °C and low threshold of -10 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x83, 0x46, 0xF6]),
+        SpiTransaction::transaction_end(),
+        // Read thresholds back
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x03, 0, 0], vec![0x03, 0x46, 0xF6]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    sensor.set_cold_junction_thresholds(Temperature::from_celsius(-10.0), Temperature::from_celsius(70.0)).unwrap();
+    assert_eq!(sensor.cold_junction_thresholds().unwrap(), (Temperature::from_celsius(-10.0), Temperature::from_celsius(70.0)));
+    // Out of range and swapped thresholds are rejected without any SPI traffic
+    assert!(matches!(sensor.set_cold_junction_thresholds(Temperature::from_celsius(-10.0), Temperature::from_celsius(128.0)), Err(Error::InvalidArgument)));
+    assert!(matches!(sensor.set_cold_junction_thresholds(Temperature::from_celsius(-129.0), Temperature::from_celsius(70.0)), Err(Error::InvalidArgument)));
+    assert!(matches!(sensor.set_cold_junction_thresholds(Temperature::from_celsius(70.0), Temperature::from_celsius(-10.0)), Err(Error::InvalidArgument)));
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_write_and_read_thermocouple_thresholds() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write high threshold of 1000.5 °C and low threshold of -0.0625 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x85, 0x3E, 0x88, 0xFF, 0xFF]),
+        SpiTransaction::transaction_end(),
+        // Read factory default thresholds of 2047.9375 °C and -2048 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x05, 0, 0, 0, 0], vec![0x05, 0x7F, 0xFF, 0x80, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    sensor.set_thermocouple_thresholds(Temperature::from_celsius(-0.0625), Temperature::from_celsius(1000.5)).unwrap();
+    assert_eq!(sensor.thermocouple_thresholds().unwrap(), (Temperature::from_celsius(-2048.0), Temperature::from_celsius(2047.9375)));
+    assert!(matches!(sensor.set_thermocouple_thresholds(Temperature::from_celsius(0.0), Temperature::from_celsius(2048.0)), Err(Error::InvalidArgument)));
+    assert!(matches!(sensor.set_thermocouple_thresholds(Temperature::from_celsius(f32::NAN), Temperature::from_celsius(100.0)), Err(Error::InvalidArgument)));
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_write_and_read_cold_junction_offset() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write offset of -1.5 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x89, 0xE8]),
+        SpiTransaction::transaction_end(),
+        // Read offset back
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x09, 0], vec![0x09, 0xE8]),
+        SpiTransaction::transaction_end(),
+        // Read offset and cj temperature register with a value of 23.5 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x09, 0, 0, 0], vec![0x09, 0xE8, 0x17, 0x80]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    sensor.set_cold_junction_offset(TemperatureDelta::from_celsius(-1.5)).unwrap();
+    assert_eq!(sensor.cold_junction_offset().unwrap(), TemperatureDelta::from_celsius(-1.5));
+    assert_eq!(sensor.raw_cold_junction_temperature().unwrap().celsius(), 25.0);
+    assert!(matches!(sensor.set_cold_junction_offset(TemperatureDelta::from_celsius(8.0)), Err(Error::InvalidArgument)));
+    assert!(matches!(sensor.set_cold_junction_offset(TemperatureDelta::from_celsius(-8.1)), Err(Error::InvalidArgument)));
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_read_and_verify_configuration() {
+    // SPI transactions
+    let spi_expectations = [
+        // Read C0 and C1 matching the stored configuration
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x00, 0, 0], vec![0x00, 0x80, 0x23]),
+        SpiTransaction::transaction_end(),
+        // Read C0 and C1 with factory defaults
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x00, 0, 0], vec![0x00, 0x00, 0x03]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    sensor.config()
+        .average_samples(max31856::AveragingMode::FourSamples)
+        .conversion_mode(max31856::CMode::AutomaticConversion);
+    assert!(sensor.verify_config().unwrap());
+    let options = sensor.read_config().unwrap();
+    assert_eq!(options, max31856::Max31856Options::default());
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_read_one_shot_without_changing_configuration() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write oneshot c0 with 50Hz rejection
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x41]),
+        SpiTransaction::transaction_end(),
+        // Read temperature register with a value of 25.0 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0,0,0], vec![0x0C, 0x01, 0x90, 0x00]),
+        SpiTransaction::transaction_end(),
+        // Write c0 without oneshot
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x01]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x81, 0x23]),
+        SpiTransaction::transaction_end(),
+    ];
+    // 185ms + 3 x 40ms for four samples with 50Hz rejection
+    let delay_expectations = [DelayTransaction::delay_us(305_000)];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut delay = CheckedDelay::new(&delay_expectations);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    sensor.config()
+        .noise_rejection_frequency(max31856::NoiseRejectionMode::Reject50Hz)
+        .average_samples(max31856::AveragingMode::FourSamples);
+    assert_eq!(sensor.read_one_shot(&mut delay).unwrap().celsius(), 25.0);
+    sensor.send_config().unwrap();
+    spi.done();
+    fault.done();
+    delay.done();
+}
+
+#[test]
+fn can_read_when_data_ready() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write oneshot c0
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x40]),
+        SpiTransaction::transaction_end(),
+        // Read temperature register with a value of 25.0 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0,0,0], vec![0x0C, 0x01, 0x90, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+    // DRDY is not asserted before the trigger and asserts after the second poll
+    let drdy_expectations = [
+        PinTransaction::get(PinState::High),
+        PinTransaction::get(PinState::High),
+        PinTransaction::get(PinState::High),
+        PinTransaction::get(PinState::Low),
+    ];
+
+    let delay_expectations = [
+        DelayTransaction::delay_us(1_000),
+        DelayTransaction::delay_us(1_000),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut drdy = PinMock::new(&drdy_expectations);
+    let mut delay = CheckedDelay::new(&delay_expectations);
+    let mut sensor = Max31856::new(&mut spi, &mut fault).with_drdy_pin(&mut drdy);
+    assert_eq!(sensor.read_when_ready(&mut delay).unwrap().celsius(), 25.0);
+    spi.done();
+    fault.done();
+    drdy.done();
+    delay.done();
+}
+
+#[test]
+fn can_discard_unread_conversion_before_trigger() {
+    // SPI transactions
+    let spi_expectations = [
+        // Read temperature register to deassert DRDY of the earlier conversion
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0], vec![0x0C, 0x01]),
+        SpiTransaction::transaction_end(),
+        // Write oneshot c0
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x40]),
+        SpiTransaction::transaction_end(),
+        // Read temperature register with a value of 100.0 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0,0,0], vec![0x0C, 0x06, 0x40, 0x00]),
+        SpiTransaction::transaction_end(),
+        // Read temperature register to deassert DRDY of the earlier conversion
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0], vec![0x0C, 0x06]),
+        SpiTransaction::transaction_end(),
+        // Write oneshot c0
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x40]),
+        SpiTransaction::transaction_end(),
+        // Read cj temperature of 25 °C, temperature of 100.9375 °C and no faults
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0, 0, 0, 0, 0],
+            vec![0x0A, 0x19, 0x00, 0x06, 0x4F, 0x00, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+    // DRDY is still asserted from an unread conversion before each trigger
+    let drdy_expectations = [
+        PinTransaction::get(PinState::Low),
+        PinTransaction::get(PinState::High),
+        PinTransaction::get(PinState::Low),
+        PinTransaction::get(PinState::Low),
+        PinTransaction::get(PinState::High),
+        PinTransaction::get(PinState::Low),
+    ];
+    let delay_expectations = [DelayTransaction::delay_us(1_000)];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut drdy = PinMock::new(&drdy_expectations);
+    let mut delay = CheckedDelay::new(&delay_expectations);
+    let mut sensor = Max31856::new(&mut spi, &mut fault).with_drdy_pin(&mut drdy);
+    assert_eq!(sensor.read_when_ready(&mut delay).unwrap().celsius(), 100.0);
+    sensor.start_conversion(0).unwrap();
+    assert!(matches!(sensor.try_read(0), Err(nb::Error::WouldBlock)));
+    assert_eq!(sensor.try_read(0).unwrap().thermocouple.celsius(), 100.9375);
+    spi.done();
+    fault.done();
+    drdy.done();
+    delay.done();
+}
+
+#[test]
+fn can_time_out_when_data_is_not_ready() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write oneshot c0
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x40]),
+        SpiTransaction::transaction_end(),
+        // Read fault status register with an overvoltage fault
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0F, 0], vec![0x0F, 0x02]),
+        SpiTransaction::transaction_end(),
+    ];
+    // DRDY never asserts during the 155 ms conversion time
+    let drdy_expectations = vec![PinTransaction::get(PinState::High); 157];
+    let delay_expectations = vec![DelayTransaction::delay_us(1_000); 155];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut drdy = PinMock::new(&drdy_expectations);
+    let mut delay = CheckedDelay::new(&delay_expectations);
+    let mut sensor = Max31856::new(&mut spi, &mut fault).with_drdy_pin(&mut drdy);
+    match sensor.read_when_ready(&mut delay) {
+        Err(Error::Device(errors)) => assert!(errors.overvoltage_undervoltage),
+        _ => panic!("Wrong result"),
+    }
+    spi.done();
+    fault.done();
+    drdy.done();
+    delay.done();
+}
+
+#[test]
+fn can_poll_one_shot_conversion() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write oneshot c0
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x40]),
+        SpiTransaction::transaction_end(),
+        // Read c0 with oneshot bit still set
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x00, 0], vec![0x00, 0x40]),
+        SpiTransaction::transaction_end(),
+        // Read c0 with oneshot bit cleared
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x00, 0], vec![0x00, 0x00]),
+        SpiTransaction::transaction_end(),
+        // Read cj temperature of 25 °C, temperature of 100.9375 °C and no faults
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0, 0, 0, 0, 0],
+            vec![0x0A, 0x19, 0x00, 0x06, 0x4F, 0x00, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    assert!(matches!(sensor.try_read(0), Err(nb::Error::Other(Error::InvalidArgument))));
+    sensor.start_conversion(1_000).unwrap();
+    // The conversion time has not passed yet, c0 is not read
+    assert!(matches!(sensor.try_read(100_000), Err(nb::Error::WouldBlock)));
+    assert!(matches!(sensor.try_read(156_000), Err(nb::Error::WouldBlock)));
+    let reading = sensor.try_read(160_000).unwrap();
+    assert_eq!(reading.thermocouple.celsius(), 100.9375);
+    assert_eq!(reading.cold_junction.celsius(), 25.0);
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_poll_automatic_conversion_with_ticks() {
+    // SPI transactions
+    let spi_expectations = [
+        // Read cj temperature of 25 °C, temperature of 1000.0 °C and no faults
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0, 0, 0, 0, 0],
+            vec![0x0A, 0x19, 0x00, 0x3E, 0x80, 0x00, 0x00]),
+        SpiTransaction::transaction_end(),
+        // Read the next conversion with a temperature of 1000.0625 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0, 0, 0, 0, 0],
+            vec![0x0A, 0x19, 0x00, 0x3E, 0x81, 0x00, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    sensor.config().conversion_mode(max31856::CMode::AutomaticConversion);
+    // The tick counter wraps around between start and read
+    sensor.start_conversion(u32::MAX - 50_000).unwrap();
+    assert!(matches!(sensor.try_read(10_000), Err(nb::Error::WouldBlock)));
+    assert_eq!(sensor.try_read(50_000).unwrap().thermocouple.celsius(), 1000.0);
+    // The same conversion is not returned twice
+    assert!(matches!(sensor.try_read(60_000), Err(nb::Error::WouldBlock)));
+    assert_eq!(sensor.try_read(150_000).unwrap().thermocouple.celsius(), 1000.0625);
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_poll_averaged_automatic_conversion_with_ticks() {
+    // SPI transactions
+    let spi_expectations = [
+        // Read cj temperature of 25 °C, temperature of 1000.0 °C and no faults
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0, 0, 0, 0, 0],
+            vec![0x0A, 0x19, 0x00, 0x3E, 0x80, 0x00, 0x00]),
+        SpiTransaction::transaction_end(),
+        // Read the next conversion with a temperature of 1000.0625 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0, 0, 0, 0, 0],
+            vec![0x0A, 0x19, 0x00, 0x3E, 0x81, 0x00, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    sensor.config().conversion_mode(max31856::CMode::AutomaticConversion)
+        .average_samples(max31856::AveragingMode::FourSamples);
+    // Four samples at 60Hz take 100ms + 3 x 16.67ms between results
+    sensor.start_conversion(0).unwrap();
+    assert!(matches!(sensor.try_read(100_000), Err(nb::Error::WouldBlock)));
+    assert!(matches!(sensor.try_read(149_000), Err(nb::Error::WouldBlock)));
+    assert_eq!(sensor.try_read(151_000).unwrap().thermocouple.celsius(), 1000.0);
+    // The same conversion is not returned twice
+    assert!(matches!(sensor.try_read(260_000), Err(nb::Error::WouldBlock)));
+    assert_eq!(sensor.try_read(302_000).unwrap().thermocouple.celsius(), 1000.0625);
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_poll_conversion_with_data_ready() {
+    // SPI transactions
+    let spi_expectations = [
+        // Read cj temperature of 25 °C, temperature of 1000.0 °C and no faults
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0, 0, 0, 0, 0],
+            vec![0x0A, 0x19, 0x00, 0x3E, 0x80, 0x00, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+    let drdy_expectations = [
+        PinTransaction::get(PinState::High),
+        PinTransaction::get(PinState::Low),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut drdy = PinMock::new(&drdy_expectations);
+    let mut sensor = Max31856::new(&mut spi, &mut fault).with_drdy_pin(&mut drdy);
+    sensor.config().conversion_mode(max31856::CMode::AutomaticConversion);
+    sensor.start_conversion(0).unwrap();
+    assert!(matches!(sensor.try_read(0), Err(nb::Error::WouldBlock)));
+    assert_eq!(sensor.try_read(0).unwrap().thermocouple.celsius(), 1000.0);
+    spi.done();
+    fault.done();
+    drdy.done();
+}
+
+#[test]
+fn can_switch_conversion_modes_with_typestate() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write normally off configuration
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x00]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x81, 0x03]),
+        SpiTransaction::transaction_end(),
+        // Write averaging of two samples
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x00]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x81, 0x13]),
+        SpiTransaction::transaction_end(),
+        // Write automatic conversion configuration
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x80]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x81, 0x13]),
+        SpiTransaction::transaction_end(),
+        // Read temperature register with a value of 25.0 °C without oneshot
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0,0,0], vec![0x0C, 0x01, 0x90, 0x00]),
+        SpiTransaction::transaction_end(),
+        // Write normally off configuration
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x00]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x81, 0x13]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let sensor = Max31856::new(&mut spi, &mut fault);
+    let mut sensor = max31856::typestate::Max31856::new(sensor).unwrap();
+    sensor.set_average_samples(max31856::AveragingMode::TwoSamples).unwrap();
+    let mut sensor = sensor.into_automatic().unwrap();
+    assert_eq!(sensor.temperature().unwrap().celsius(), 25.0);
+    let sensor = sensor.into_normally_off().unwrap();
+    let _ = sensor.release();
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_read_voltage() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write voltage mode with gain 8
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x80]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x81, 0x08]),
+        SpiTransaction::transaction_end(),
+        // Read code of 2^14
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0,0,0], vec![0x0C, 0x08, 0x00, 0x00]),
+        SpiTransaction::transaction_end(),
+        // Read code of -2^14
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0,0,0], vec![0x0C, 0xF8, 0x00, 0x00]),
+        SpiTransaction::transaction_end(),
+        // Write oneshot c0 in normally off mode
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x40]),
+        SpiTransaction::transaction_end(),
+        // Read code of 2^15 after the conversion time
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0,0,0], vec![0x0C, 0x10, 0x00, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+    let delay_expectations = [DelayTransaction::delay_us(155_000)];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut delay = CheckedDelay::new(&delay_expectations);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    assert!(matches!(sensor.read_voltage(&mut delay), Err(Error::InvalidArgument)));
+    sensor.config()
+        .conversion_mode(max31856::CMode::AutomaticConversion)
+        .type_selection(max31856::ThermocoupleType::VModeGain8Type);
+    sensor.send_config().unwrap();
+    assert_eq!(sensor.read_voltage(&mut delay).unwrap(), 9765.625);
+    assert_eq!(sensor.read_voltage(&mut delay).unwrap(), -9765.625);
+    sensor.config().conversion_mode(max31856::CMode::NormallyOff);
+    assert_eq!(sensor.read_voltage(&mut delay).unwrap(), 19531.25);
+    spi.done();
+    fault.done();
+    delay.done();
+}
+
+#[test]
+fn cannot_read_temperature_in_voltage_mode() {
+    let mut spi = SpiMock::new(&[]);
+    let mut fault = PinMock::new(&[]);
+    let mut delay = CheckedDelay::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    sensor.config().type_selection(max31856::ThermocoupleType::VModeGain32Type);
+    assert!(matches!(sensor.temperature(), Err(Error::InvalidArgument)));
+    assert!(matches!(sensor.temperature_raw(), Err(Error::InvalidArgument)));
+    assert!(matches!(sensor.temperature_millicelsius(), Err(Error::InvalidArgument)));
+    assert!(matches!(sensor.read_one_shot(&mut delay), Err(Error::InvalidArgument)));
+    spi.done();
+    fault.done();
+    delay.done();
+}
+
+struct FixedColdJunction(f32);
+
+impl max31856::ColdJunctionSource for FixedColdJunction {
+    type Error = max31856::ColdJunctionErrorKind;
+
+    fn cold_junction_temperature(&mut self) -> Result<Temperature, Self::Error> {
+        if self.0.is_nan() {
+            return Err(max31856::ColdJunctionErrorKind::NotReady);
+        }
+        Ok(Temperature::from_celsius(self.0))
+    }
+}
+
+#[test]
+fn can_read_one_shot_with_external_cold_junction() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write c0 with cold-junction sensor disabled
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x08]),
+        SpiTransaction::transaction_end(),
+        // Write cj temperature of 30.5 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x8A, 0x1E, 0x80]),
+        SpiTransaction::transaction_end(),
+        // Write oneshot c0 with cold-junction sensor disabled
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x48]),
+        SpiTransaction::transaction_end(),
+        // Read temperature register with a value of 100.9375 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0,0,0], vec![0x0C, 0x06, 0x4F, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+    let delay_expectations = [DelayTransaction::delay_us(155_000)];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut delay = CheckedDelay::new(&delay_expectations);
+    let mut source = FixedColdJunction(30.5);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    // The internal sensor has to be disabled first
+    assert!(matches!(sensor.set_cold_junction_temperature(Temperature::from_celsius(30.5)), Err(Error::InvalidArgument)));
+    sensor.config().cold_junction_sensor_disable(true);
+    let temperature = sensor.read_one_shot_with_cold_junction(&mut source, &mut delay).unwrap();
+    assert_eq!(temperature.celsius(), 100.9375);
+    // Errors of the source keep their kind, nothing is written
+    let mut source = FixedColdJunction(f32::NAN);
+    let result = sensor.read_one_shot_with_cold_junction(&mut source, &mut delay);
+    assert_eq!(result, Err(Error::ColdJunctionSource { kind: max31856::ColdJunctionErrorKind::NotReady }));
+    spi.done();
+    fault.done();
+    delay.done();
+}
+
+#[test]
+fn can_read_all_in_one_transaction() {
+    // SPI transactions
+    let spi_expectations = [
+        // Read cj temperature of -25 °C, temperature of -250.0 °C
+        // and overvoltage with thermocouple low fault
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0, 0, 0, 0, 0],
+            vec![0x0A, 0xE7, 0x00, 0xF0, 0x60, 0x00, 0x06]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    let reading = sensor.read_all().unwrap();
+    assert_eq!(reading.cold_junction.celsius(), -25.0);
+    assert_eq!(reading.thermocouple.celsius(), -250.0);
+    assert_eq!(reading.faults, max31856::DeviceErrors {
+        overvoltage_undervoltage: true,
+        thermocouple_low: true,
+        ..Default::default()
+    });
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_get_faults_as_data() {
+    // SPI transactions
+    let spi_expectations = [
+        // Read overvoltage, open circuit and cold junction high faults
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0F, 0], vec![0x0F, 0x23]),
+        SpiTransaction::transaction_end(),
+        // Read no faults
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0F, 0], vec![0x0F, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    let faults = sensor.faults().unwrap();
+    assert!(faults.any());
+    assert!(faults.is_critical());
+    assert!(faults.masks_other_faults());
+    let active: Vec<max31856::Fault> = faults.iter().collect();
+    assert_eq!(active, [
+        max31856::Fault::ColdJunctionHigh,
+        max31856::Fault::OvervoltageUndervoltage,
+        max31856::Fault::OpenCircuit,
+    ]);
+    let faults = sensor.faults().unwrap();
+    assert!(!faults.any());
+    assert!(!faults.is_critical());
+    assert_eq!(faults.iter().count(), 0);
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_clear_faults_in_interrupt_mode() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write fault clear bit in interrupt mode without oneshot
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x06]),
+        SpiTransaction::transaction_end(),
+        // Read open circuit fault
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0F, 0], vec![0x0F, 0x01]),
+        SpiTransaction::transaction_end(),
+        // Automatically clear after reporting
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x06]),
+        SpiTransaction::transaction_end(),
+        // Read no faults, nothing to clear
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0F, 0], vec![0x0F, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    sensor.config()
+        .fault_mode(max31856::FaultModes::Interrupt)
+        .one_shot_conversion(max31856::OneShot::OneShotConversion);
+    sensor.clear_faults().unwrap();
+    sensor.set_auto_clear_faults(true);
+    assert!(sensor.faults().unwrap().open_circuit);
+    assert!(!sensor.faults().unwrap().any());
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_poll_fault_pin() {
+    // SPI transactions
+    let spi_expectations = [
+        // Read thermocouple high fault
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0F, 0], vec![0x0F, 0x08]),
+        SpiTransaction::transaction_end(),
+        // Read thermocouple high fault again after split
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0F, 0], vec![0x0F, 0x08]),
+        SpiTransaction::transaction_end(),
+    ];
+    let fault_expectations = [
+        PinTransaction::get(PinState::High),
+        PinTransaction::get(PinState::Low),
+        PinTransaction::get(PinState::Low),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&fault_expectations);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    assert_eq!(sensor.poll_fault().unwrap(), None);
+    let faults = sensor.poll_fault().unwrap().unwrap();
+    assert!(faults.thermocouple_high);
+    // The pin can be handed to an interrupt handler
+    let (mut sensor, mut fault_pin) = sensor.split_fault_pin();
+    assert!(fault_pin.is_asserted().unwrap());
+    assert!(sensor.faults().unwrap().thermocouple_high);
+    let _ = sensor.join_fault_pin(fault_pin);
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_use_sensor_without_pins() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write oneshot c0
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x40]),
+        SpiTransaction::transaction_end(),
+        // Read temperature register with a value of 25.0 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0,0,0], vec![0x0C, 0x01, 0x90, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+    let fault_expectations = [PinTransaction::get(PinState::High)];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&fault_expectations);
+    let mut sensor = Max31856::new_without_pins(&mut spi);
+    assert_eq!(sensor.temperature().unwrap().celsius(), 25.0);
+    // Pin features become available once the pin is supplied
+    let mut sensor = sensor.with_fault_pin(&mut fault);
+    assert_eq!(sensor.poll_fault().unwrap(), None);
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn errors_keep_pin_and_register() {
+    use embedded_hal::digital::ErrorKind;
+    use embedded_hal_mock::eh1::MockError;
+    use max31856::PinId;
+
+    let fault_expectations = [
+        PinTransaction::get(PinState::Low).with_error(MockError::Io(std::io::ErrorKind::Other)),
+    ];
+    let mut fault = PinMock::new(&fault_expectations);
+    let mut spi = SpiMock::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    let error = sensor.is_fault_asserted().unwrap_err();
+    assert_eq!(error, Error::Pin { kind: ErrorKind::Other, pin: PinId::Fault });
+    assert!(format!("{}", error).starts_with("error reading Fault pin"));
+
+    let error = Error::Spi { kind: embedded_hal::spi::ErrorKind::Other, register: 0x0C };
+    assert!(format!("{}", error).starts_with("SPI error accessing register 0x0C"));
+    spi.done();
+    fault.done();
+}
+
+#[test]
+fn can_compensate_voltage_in_software() {
+    // SPI transactions
+    let spi_expectations = [
+        // Read cj temperature of 25 °C and voltage code of 5194 (3095.95 µV)
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0, 0, 0, 0, 0],
+            vec![0x0A, 0x19, 0x00, 0x02, 0x89, 0x40, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut delay = CheckedDelay::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    let result = sensor.read_compensated(&max31856::ThermocoupleType::KType, &mut delay);
+    assert!(matches!(result, Err(Error::InvalidArgument)));
+    sensor.config()
+        .conversion_mode(max31856::CMode::AutomaticConversion)
+        .type_selection(max31856::ThermocoupleType::VModeGain8Type);
+    let reading = sensor.read_compensated(&max31856::ThermocoupleType::KType, &mut delay).unwrap();
+    assert_eq!(reading.cold_junction.celsius(), 25.0);
+    // 3.096 mV + 1.000 mV for 25 °C is 4.096 mV, which is 100 °C for K type
+    assert!((reading.thermocouple.celsius() - 100.0).abs() < 0.05);
+    assert_eq!(reading.faults, max31856::DeviceErrors::default());
+    spi.done();
+    fault.done();
+    delay.done();
+}
+
+#[test]
+fn can_compare_compensation_with_chip() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write oneshot c0
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x40]),
+        SpiTransaction::transaction_end(),
+        // Read cj temperature of 25 °C and voltage code of 5194 (3095.95 µV)
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0, 0, 0, 0, 0],
+            vec![0x0A, 0x19, 0x00, 0x02, 0x89, 0x40, 0x00]),
+        SpiTransaction::transaction_end(),
+        // Write K type to c1 and trigger the second conversion
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x81, 0x03]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x40]),
+        SpiTransaction::transaction_end(),
+        // Read temperature register with a value of 100.0625 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0,0,0], vec![0x0C, 0x06, 0x41, 0x00]),
+        SpiTransaction::transaction_end(),
+        // Restore voltage mode with gain 8 in c1
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x81, 0x08]),
+        SpiTransaction::transaction_end(),
+    ];
+    let delay_expectations = [
+        DelayTransaction::delay_us(155_000),
+        DelayTransaction::delay_us(155_000),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut delay = CheckedDelay::new(&delay_expectations);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    sensor.config().type_selection(max31856::ThermocoupleType::VModeGain8Type);
+    let result = sensor.read_compensated_with_chip(max31856::ThermocoupleType::VModeGain32Type, &mut delay);
+    assert!(matches!(result, Err(Error::InvalidArgument)));
+    let (reading, chip) = sensor.read_compensated_with_chip(max31856::ThermocoupleType::KType, &mut delay).unwrap();
+    assert!((reading.thermocouple.celsius() - 100.0).abs() < 0.05);
+    assert_eq!(chip.celsius(), 100.0625);
+    // Automatic conversions could mix both thermocouple types
+    sensor.config().conversion_mode(max31856::CMode::AutomaticConversion);
+    let result = sensor.read_compensated_with_chip(max31856::ThermocoupleType::KType, &mut delay);
+    assert!(matches!(result, Err(Error::InvalidArgument)));
+    spi.done();
+    fault.done();
+    delay.done();
+}
+
+#[test]
+fn can_compensate_with_custom_linearization() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write voltage mode with gain 32
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x80]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x81, 0x0C]),
+        SpiTransaction::transaction_end(),
+        // Read cj temperature of 0 °C and voltage code of 2^14 (2441.4 µV)
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0, 0, 0, 0, 0],
+            vec![0x0A, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut fault = PinMock::new(&[]);
+    let mut delay = CheckedDelay::new(&[]);
+    let mut sensor = Max31856::new(&mut spi, &mut fault);
+    sensor.config()
+        .conversion_mode(max31856::CMode::AutomaticConversion)
+        .type_selection(max31856::ThermocoupleType::VModeGain32Type);
+    sensor.send_config().unwrap();
+    let reading = sensor.read_compensated(&max31856::linearization::TYPE_C, &mut delay).unwrap();
+    // 2.441 mV is 161.56 °C for type C
+    assert!((reading.thermocouple.celsius() - 161.56).abs() < 0.01);
+    spi.done();
+    fault.done();
+    delay.done();
+}
+
+#[test]
+fn can_read_temperatures_without_floats() {
+    // SPI transactions
+    let spi_expectations = [
+        // Read temperature of -250.0 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0, 0, 0], vec![0x0C, 0xF0, 0x60, 0x00]),
+        SpiTransaction::transaction_end(),
+        // Read temperature of 100.0078125 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0, 0, 0], vec![0x0C, 0x06, 0x40, 0x20]),
+        SpiTransaction::transaction_end(),
+        // Read cj temperature of -25.015625 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0], vec![0x0A, 0xE6, 0xFC]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0], vec![0x0A, 0xE6, 0xFC]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut sensor = Max31856::new_without_pins(&mut spi);
+    sensor.config().conversion_mode(max31856::CMode::AutomaticConversion);
+    assert_eq!(sensor.temperature_raw().unwrap(), -32000);
+    // 100.0078125 °C is rounded to 100008 m°C
+    assert_eq!(sensor.temperature_millicelsius().unwrap(), 100_008);
+    assert_eq!(sensor.cold_junction_temperature_raw().unwrap(), -1601);
+    assert_eq!(sensor.cold_junction_temperature_millicelsius().unwrap(), -25_016);
+    spi.done();
+}
+
+#[test]
+fn can_configure_and_read_all_without_floats() {
+    // SPI transactions
+    let spi_expectations = [
+        // Write cj thresholds of -10 °C and 71 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x83, 0x47, 0xF6]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x03, 0, 0], vec![0x03, 0x46, 0xF6]),
+        SpiTransaction::transaction_end(),
+        // Write tc thresholds of -200 °C and 1300.0625 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x85, 0x51, 0x41, 0xF3, 0x80]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x05, 0, 0, 0, 0], vec![0x05, 0x51, 0x41, 0xF3, 0x81]),
+        SpiTransaction::transaction_end(),
+        // Write cj offset of -1.5 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x89, 0xE8]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x09, 0], vec![0x09, 0xE8]),
+        SpiTransaction::transaction_end(),
+        // Write cj temperature of 30.5 °C with the sensor disabled
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x80, 0x08]),
+        SpiTransaction::transaction_end(),
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write_vec(vec![0x8A, 0x1E, 0x80]),
+        SpiTransaction::transaction_end(),
+        // Read cj temperature of 25 °C, temperature of 100.9375 °C and no faults
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0, 0, 0, 0, 0],
+            vec![0x0A, 0x19, 0x00, 0x06, 0x4F, 0x00, 0x00]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut sensor = Max31856::new_without_pins(&mut spi);
+    // Halves of a step are rounded away from zero
+    sensor.set_cold_junction_thresholds_millicelsius(-10_400, 70_500).unwrap();
+    assert_eq!(sensor.cold_junction_thresholds_millicelsius().unwrap(), (-10_000, 70_000));
+    sensor.set_thermocouple_thresholds_millicelsius(-200_031, 1_300_032).unwrap();
+    // Half m°C of odd steps are rounded up
+    assert_eq!(sensor.thermocouple_thresholds_millicelsius().unwrap(), (-199_937, 1_300_063));
+    assert!(matches!(sensor.set_cold_junction_offset_millicelsius(8_000), Err(Error::InvalidArgument)));
+    sensor.set_cold_junction_offset_millicelsius(-1_500).unwrap();
+    assert_eq!(sensor.cold_junction_offset_millicelsius().unwrap(), -1_500);
+    sensor.config().cold_junction_sensor_disable(true);
+    sensor.set_cold_junction_temperature_millicelsius(30_500).unwrap();
+    let reading = sensor.read_all_raw().unwrap();
+    assert_eq!(reading.thermocouple, 12920);
+    assert_eq!(reading.cold_junction, 1600);
+    assert_eq!(reading.thermocouple_millicelsius(), 100_938);
+    assert_eq!(reading.cold_junction_millicelsius(), 25_000);
+    assert_eq!(max31856::Reading::from(reading).thermocouple.celsius(), 100.9375);
+    spi.done();
+}
+
+#[cfg(feature = "fixed")]
+#[test]
+fn can_read_fixed_point_temperatures() {
+    use fixed::types::I16F16;
+    // SPI transactions
+    let spi_expectations = [
+        // Read temperature of -250.0078125 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0C, 0, 0, 0], vec![0x0C, 0xF0, 0x5F, 0xE0]),
+        SpiTransaction::transaction_end(),
+        // Read cj temperature of 25.015625 °C
+        SpiTransaction::transaction_start(),
+        SpiTransaction::transfer_in_place(vec![0x0A, 0, 0], vec![0x0A, 0x19, 0x04]),
+        SpiTransaction::transaction_end(),
+    ];
+
+    let mut spi = SpiMock::new(&spi_expectations);
+    let mut sensor = Max31856::new_without_pins(&mut spi);
+    sensor.config().conversion_mode(max31856::CMode::AutomaticConversion);
+    assert_eq!(sensor.temperature_fixed().unwrap(), I16F16::from_num(-250.0078125));
+    assert_eq!(sensor.cold_junction_temperature_fixed().unwrap(), I16F16::from_num(25.015625));
+    spi.done();
+}
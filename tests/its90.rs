@@ -0,0 +1,85 @@
+use max31856::its90::{emf_range, emf_to_temperature, emf_to_temperature_f32, temperature_range,
+    temperature_to_emf, temperature_to_emf_f32};
+use max31856::{Error, ThermocoupleType};
+
+// Values from the NIST ITS-90 thermocouple tables in mV
+const REFERENCE: &[(ThermocoupleType, f64, f64)] = &[
+    (ThermocoupleType::BType, 1000.0, 4.834),
+    (ThermocoupleType::BType, 1800.0, 13.591),
+    (ThermocoupleType::EType, -100.0, -5.237),
+    (ThermocoupleType::EType, 100.0, 6.319),
+    (ThermocoupleType::JType, -100.0, -4.633),
+    (ThermocoupleType::JType, 100.0, 5.269),
+    (ThermocoupleType::JType, 1000.0, 57.953),
+    (ThermocoupleType::KType, -100.0, -3.554),
+    (ThermocoupleType::KType, 100.0, 4.096),
+    (ThermocoupleType::KType, 1000.0, 41.276),
+    (ThermocoupleType::NType, 100.0, 2.774),
+    (ThermocoupleType::NType, 1000.0, 36.256),
+    (ThermocoupleType::RType, 100.0, 0.647),
+    (ThermocoupleType::RType, 1000.0, 10.506),
+    (ThermocoupleType::SType, 100.0, 0.646),
+    (ThermocoupleType::SType, 1000.0, 9.587),
+    (ThermocoupleType::TType, -100.0, -3.379),
+    (ThermocoupleType::TType, 100.0, 4.279),
+];
+
+#[test]
+fn temperature_to_emf_matches_nist_tables() {
+    for &(thermocouple, celsius, millivolts) in REFERENCE {
+        let emf = temperature_to_emf(thermocouple, celsius).unwrap();
+        assert!((emf - millivolts).abs() < 0.0005, "{:?} {} °C: {} mV", thermocouple, celsius, emf);
+    }
+}
+
+#[test]
+fn emf_to_temperature_matches_nist_tables() {
+    for &(thermocouple, celsius, millivolts) in REFERENCE {
+        let temperature = emf_to_temperature(thermocouple, millivolts).unwrap();
+        // Table values are rounded to 1 µV, which is up to 0.1 °C for B, R and S types
+        assert!((temperature - celsius).abs() < 0.1, "{:?} {} mV: {} °C", thermocouple, millivolts, temperature);
+    }
+}
+
+#[test]
+fn inverse_is_within_error_bounds() {
+    let types = [
+        ThermocoupleType::BType, ThermocoupleType::EType, ThermocoupleType::JType, ThermocoupleType::KType,
+        ThermocoupleType::NType, ThermocoupleType::RType, ThermocoupleType::SType, ThermocoupleType::TType,
+    ];
+    for &thermocouple in types.iter() {
+        let (low, high) = emf_range(thermocouple).unwrap();
+        let (t_low, t_high) = temperature_range(thermocouple).unwrap();
+        let mut celsius = t_low.max(emf_to_temperature(thermocouple, low).unwrap().ceil());
+        while celsius < t_high.min(emf_to_temperature(thermocouple, high).unwrap().floor()) {
+            let emf = temperature_to_emf(thermocouple, celsius).unwrap();
+            let roundtrip = emf_to_temperature(thermocouple, emf).unwrap();
+            assert!((roundtrip - celsius).abs() < 0.065, "{:?} {} °C: {} °C", thermocouple, celsius, roundtrip);
+            celsius += 1.0;
+        }
+    }
+}
+
+#[test]
+fn rejects_out_of_range_arguments() {
+    assert!(matches!(temperature_to_emf(ThermocoupleType::KType, 1400.0), Err(Error::InvalidArgument)));
+    assert!(matches!(emf_to_temperature(ThermocoupleType::TType, -6.0), Err(Error::InvalidArgument)));
+    assert!(matches!(temperature_to_emf(ThermocoupleType::VModeGain8Type, 25.0), Err(Error::InvalidArgument)));
+    assert!(matches!(temperature_to_emf(ThermocoupleType::KType, f64::NAN), Err(Error::InvalidArgument)));
+}
+
+#[test]
+fn f32_variants() {
+    let emf = temperature_to_emf_f32(ThermocoupleType::KType, 100.0).unwrap();
+    assert!((emf - 4.096).abs() < 0.0005);
+    let temperature = emf_to_temperature_f32(ThermocoupleType::KType, emf).unwrap();
+    assert!((temperature - 100.0).abs() < 0.05);
+}
+
+#[test]
+fn can_compensate_cold_junction() {
+    let temperature = max31856::its90::compensate(ThermocoupleType::KType, 3.096, 25.0).unwrap();
+    assert!((temperature - 100.0).abs() < 0.05);
+    let temperature = max31856::its90::compensate(ThermocoupleType::JType, 0.0, 25.0).unwrap();
+    assert!((temperature - 25.0).abs() < 0.05);
+}
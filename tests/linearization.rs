@@ -0,0 +1,49 @@
+use max31856::linearization::{Linearization, Polynomial, Polynomials, Table, TYPE_C};
+use max31856::{Error, ThermocoupleType};
+
+#[test]
+fn built_in_type_c() {
+    // Values from the ASTM E988 table in mV
+    assert!((TYPE_C.temperature_to_emf(100.0).unwrap() - 1.451).abs() < 0.0005);
+    assert!((TYPE_C.temperature_to_emf(2315.0).unwrap() - 37.070).abs() < 0.0005);
+    for &celsius in [0.0, 100.0, 630.615, 1000.0, 2000.0, 2315.0].iter() {
+        let emf = TYPE_C.temperature_to_emf(celsius).unwrap();
+        assert!((TYPE_C.emf_to_temperature(emf).unwrap() - celsius).abs() < 1e-4);
+    }
+    assert!(matches!(TYPE_C.emf_to_temperature(38.0), Err(Error::InvalidArgument)));
+}
+
+#[test]
+fn can_use_custom_table() {
+    let points = [(0.0, 0.0), (100.0, 1.0), (200.0, 3.0)];
+    let table = Table::new(&points).unwrap();
+    assert_eq!(table.temperature_to_emf(150.0).unwrap(), 2.0);
+    assert_eq!(table.emf_to_temperature(0.5).unwrap(), 50.0);
+    // Reference junction at 50 °C adds 0.5 mV
+    assert_eq!(table.compensate(1.5, 50.0).unwrap(), 150.0);
+    assert!(matches!(Table::new(&[(0.0, 0.0)]), Err(Error::InvalidArgument)));
+    assert!(matches!(Table::new(&[(0.0, 0.0), (100.0, 0.0)]), Err(Error::InvalidArgument)));
+}
+
+#[test]
+fn can_use_custom_polynomials() {
+    let forward = [
+        Polynomial { lower: 0.0, upper: 100.0, coefficients: &[0.0, 0.01] },
+        Polynomial { lower: 100.0, upper: 200.0, coefficients: &[-1.0, 0.02] },
+    ];
+    let inverse = [Polynomial { lower: 0.0, upper: 1.0, coefficients: &[0.0, 100.0] }];
+    let polynomials = Polynomials::new(&forward).unwrap();
+    assert_eq!(polynomials.temperature_to_emf(150.0).unwrap(), 2.0);
+    assert!((polynomials.emf_to_temperature(2.0).unwrap() - 150.0).abs() < 1e-9);
+    let polynomials = polynomials.with_inverse(&inverse).unwrap();
+    assert_eq!(polynomials.emf_to_temperature(0.5).unwrap(), 50.0);
+    assert!(matches!(polynomials.emf_to_temperature(2.0), Err(Error::InvalidArgument)));
+    assert!(matches!(Polynomials::new(&forward[1..]).unwrap().with_inverse(&[]), Err(Error::InvalidArgument)));
+    assert!(matches!(Polynomials::new(&[forward[1], forward[0]]), Err(Error::InvalidArgument)));
+}
+
+#[test]
+fn thermocouple_types_use_its90() {
+    let emf = ThermocoupleType::KType.temperature_to_emf(100.0).unwrap();
+    assert_eq!(emf, max31856::its90::temperature_to_emf(ThermocoupleType::KType, 100.0).unwrap());
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "serde")]
+
+use max31856::{AveragingMode, CMode, DeviceErrors, FaultModes, Max31856Options, NoiseRejectionMode,
+    OCFaultModes, OneShot, ThermocoupleType};
+use serde::{de::DeserializeOwned, Serialize};
+
+// Checks the stored name of a variant in both directions
+fn assert_name<T>(value: T, name: &str)
+where
+    T: Serialize + DeserializeOwned + PartialEq + core::fmt::Debug,
+{
+    let json = format!("\"{}\"", name);
+    assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value);
+}
+
+#[test]
+fn variant_names_are_stable() {
+    assert_name(CMode::NormallyOff, "normally_off");
+    assert_name(CMode::AutomaticConversion, "automatic");
+    assert_name(OneShot::NoConversion, "none");
+    assert_name(OneShot::OneShotConversion, "one_shot");
+    assert_name(OCFaultModes::Disabled, "disabled");
+    assert_name(OCFaultModes::Enabled5k, "5k");
+    assert_name(OCFaultModes::Enabled40k, "40k_below_2ms");
+    assert_name(OCFaultModes::Enabled40k2ms, "40k_above_2ms");
+    assert_name(FaultModes::Comparator, "comparator");
+    assert_name(FaultModes::Interrupt, "interrupt");
+    assert_name(NoiseRejectionMode::Reject60Hz, "60hz");
+    assert_name(NoiseRejectionMode::Reject50Hz, "50hz");
+    assert_name(AveragingMode::OneSample, "1");
+    assert_name(AveragingMode::TwoSamples, "2");
+    assert_name(AveragingMode::FourSamples, "4");
+    assert_name(AveragingMode::EightSamples, "8");
+    assert_name(AveragingMode::SixteenSamples, "16");
+    assert_name(ThermocoupleType::BType, "b");
+    assert_name(ThermocoupleType::EType, "e");
+    assert_name(ThermocoupleType::JType, "j");
+    assert_name(ThermocoupleType::KType, "k");
+    assert_name(ThermocoupleType::NType, "n");
+    assert_name(ThermocoupleType::RType, "r");
+    assert_name(ThermocoupleType::SType, "s");
+    assert_name(ThermocoupleType::TType, "t");
+    assert_name(ThermocoupleType::VModeGain8Type, "voltage_gain_8");
+    assert_name(ThermocoupleType::VModeGain32Type, "voltage_gain_32");
+}
+
+#[test]
+fn can_roundtrip_options() {
+    let mut options = Max31856Options::new();
+    options.conversion_mode(CMode::AutomaticConversion)
+        .noise_rejection_frequency(NoiseRejectionMode::Reject50Hz)
+        .average_samples(AveragingMode::FourSamples)
+        .type_selection(ThermocoupleType::TType);
+    let json = serde_json::to_string(&options).unwrap();
+    assert!(json.contains("\"conversion_mode\":\"automatic\""));
+    assert!(json.contains("\"noise_rejection_frequency\":\"50hz\""));
+    assert!(json.contains("\"average_samples\":\"4\""));
+    assert!(json.contains("\"type_selection\":\"t\""));
+    let decoded: Max31856Options = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, options);
+}
+
+#[test]
+fn can_roundtrip_device_errors() {
+    let errors = DeviceErrors { open_circuit: true, ..Default::default() };
+    let json = serde_json::to_string(&errors).unwrap();
+    assert!(json.contains("\"open_circuit\":true"));
+    let decoded: DeviceErrors = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, errors);
+}
@@ -0,0 +1,42 @@
+use max31856::{Temperature, TemperatureDelta};
+
+#[test]
+fn can_convert_units() {
+    let boiling = Temperature::from_celsius(100.0);
+    assert_eq!(boiling.fahrenheit(), 212.0);
+    assert_eq!(boiling.kelvin(), 373.15);
+    assert_eq!(Temperature::from_fahrenheit(-40.0).celsius(), -40.0);
+    assert_eq!(Temperature::from_kelvin(273.15).celsius(), 0.0);
+    let delta = TemperatureDelta::from_fahrenheit(9.0);
+    assert_eq!(delta.celsius(), 5.0);
+    assert_eq!(delta.kelvin(), 5.0);
+    assert_eq!(TemperatureDelta::from_kelvin(2.0).fahrenheit(), 3.6);
+}
+
+#[test]
+fn can_do_arithmetic() {
+    let low = Temperature::from_celsius(20.0);
+    let high = Temperature::from_celsius(25.5);
+    assert_eq!(high - low, TemperatureDelta::from_celsius(5.5));
+    assert_eq!(low + TemperatureDelta::from_celsius(5.5), high);
+    assert_eq!(high - TemperatureDelta::from_celsius(5.5), low);
+    let mut temperature = low;
+    temperature += TemperatureDelta::from_celsius(1.0) * 2.0;
+    temperature -= -TemperatureDelta::from_celsius(0.5);
+    assert_eq!(temperature, Temperature::from_celsius(22.5));
+    assert!(low < high);
+}
+
+#[cfg(feature = "uom")]
+#[test]
+fn can_convert_to_uom() {
+    use uom::si::f32::{TemperatureInterval, ThermodynamicTemperature};
+    use uom::si::{temperature_interval, thermodynamic_temperature::kelvin};
+
+    let temperature: ThermodynamicTemperature = Temperature::from_celsius(25.0).into();
+    assert_eq!(temperature.get::<kelvin>(), 298.15);
+    assert_eq!(Temperature::from(temperature).celsius(), 25.0);
+    let delta: TemperatureInterval = TemperatureDelta::from_celsius(2.0).into();
+    assert_eq!(delta.get::<temperature_interval::degree_fahrenheit>(), 3.6);
+    assert_eq!(TemperatureDelta::from(delta).celsius(), 2.0);
+}